@@ -0,0 +1,119 @@
+// =============================================================================
+//! - AI Profile for CroftSoft Mars
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::ai::target_selector::{TargetCandidate, TargetSelector};
+use crate::constant::{
+  A_STAR_AMMO_DUMP_HAZARD_WEIGHT, A_STAR_EXPOSURE_WEIGHT, A_STAR_LOOPS,
+  TANK_VETERAN_LEVEL_MIN,
+};
+use crate::model::tank::TurretIdleMode;
+
+// Personality parameters for a tank operator
+#[derive(Clone, Copy, Debug)]
+pub struct AiProfile {
+  // Maximum random error in radians when aiming the turret
+  pub accuracy_jitter: f64,
+  // Multiplier for the firing and drift probabilities
+  pub aggression: f64,
+  // Path planning cost weight for time spent near ammo dumps that could blow
+  // up; zero disables the term
+  pub ammo_dump_hazard_weight: f64,
+  // Sidesteps out of the line of fire of incoming bullets
+  pub evasion_enabled: bool,
+  // Path planning cost weight for time spent within range of enemy turrets;
+  // zero disables the term
+  pub exposure_weight: f64,
+  // Maximum number of A* loops per path planning
+  pub planning_budget: usize,
+  // Seconds before switching to a newly selected target
+  pub reaction_delay: f64,
+  // Seeks an ammo dump when the ammo is at or below this
+  pub resupply_threshold: usize,
+  // Scores the candidate targets where the highest score is selected
+  pub target_scorer: fn(&TargetCandidate) -> f64,
+  // What the turret does when there is no target
+  pub turret_idle_mode: TurretIdleMode,
+}
+
+impl AiProfile {
+  pub const ROOKIE: Self = Self {
+    accuracy_jitter: 0.2,
+    aggression: 0.5,
+    ammo_dump_hazard_weight: 0.,
+    evasion_enabled: false,
+    exposure_weight: 0.,
+    planning_budget: A_STAR_LOOPS / 2,
+    reaction_delay: 1.,
+    resupply_threshold: 0,
+    target_scorer: TargetSelector::nearest_score,
+    turret_idle_mode: TurretIdleMode::Forward,
+  };
+
+  pub const SNIPER: Self = Self {
+    accuracy_jitter: 0.,
+    aggression: 0.5,
+    ammo_dump_hazard_weight: A_STAR_AMMO_DUMP_HAZARD_WEIGHT,
+    evasion_enabled: true,
+    exposure_weight: 2. * A_STAR_EXPOSURE_WEIGHT,
+    planning_budget: A_STAR_LOOPS,
+    reaction_delay: 0.5,
+    resupply_threshold: 2,
+    target_scorer: TargetSelector::default_score,
+    turret_idle_mode: TurretIdleMode::Hold,
+  };
+
+  pub const VETERAN: Self = Self {
+    accuracy_jitter: 0.05,
+    aggression: 1.5,
+    ammo_dump_hazard_weight: A_STAR_AMMO_DUMP_HAZARD_WEIGHT,
+    evasion_enabled: true,
+    exposure_weight: A_STAR_EXPOSURE_WEIGHT,
+    planning_budget: A_STAR_LOOPS * 2,
+    reaction_delay: 0.25,
+    resupply_threshold: 1,
+    target_scorer: TargetSelector::default_score,
+    turret_idle_mode: TurretIdleMode::Sweep,
+  };
+
+  // Rookies at the lower difficulty levels and a mix of veterans and snipers
+  // at the higher levels for the tank at the index
+  pub fn for_level(
+    index: usize,
+    level: usize,
+  ) -> Self {
+    if level < TANK_VETERAN_LEVEL_MIN {
+      AiProfile::ROOKIE
+    } else if index % 3 == 2 {
+      AiProfile::SNIPER
+    } else {
+      AiProfile::VETERAN
+    }
+  }
+}
+
+impl Default for AiProfile {
+  fn default() -> Self {
+    Self {
+      accuracy_jitter: 0.,
+      aggression: 1.,
+      ammo_dump_hazard_weight: A_STAR_AMMO_DUMP_HAZARD_WEIGHT,
+      evasion_enabled: false,
+      exposure_weight: A_STAR_EXPOSURE_WEIGHT,
+      planning_budget: A_STAR_LOOPS,
+      reaction_delay: 0.,
+      resupply_threshold: 0,
+      target_scorer: TargetSelector::default_score,
+      turret_idle_mode: TurretIdleMode::Forward,
+    }
+  }
+}
@@ -0,0 +1,89 @@
+// =============================================================================
+//! - Cover Finder for CroftSoft Mars
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constant::{A_STAR_STEP_SIZE, BOUNDS};
+use crate::world::World;
+use com_croftsoft_core::math::geom::circle::{Circle, CircleAccessor};
+use com_croftsoft_core::math::geom::point_2dd::Point2DD;
+use com_croftsoft_core::math::geom::rectangle::Rectangle;
+use std::rc::Weak;
+
+pub struct CoverFinder {
+  world: Weak<dyn World>,
+}
+
+impl CoverFinder {
+  // Positions directly behind the obstacles as seen from the threat where the
+  // tank would fit, closest to the tank first
+  pub fn find_cover_circles(
+    &self,
+    tank_circle: &Circle,
+    threat_center: &Point2DD,
+  ) -> Vec<Circle> {
+    let tank_radius: f64 = tank_circle.radius;
+    let tank_center: Point2DD = tank_circle.get_center_point_2dd();
+    let center_bounds = Rectangle {
+      x_max: BOUNDS.x_max - tank_radius,
+      x_min: BOUNDS.x_min + tank_radius,
+      y_max: BOUNDS.y_max - tank_radius,
+      y_min: BOUNDS.y_min + tank_radius,
+    };
+    let world = self.world.upgrade().unwrap();
+    let obstacles = world.get_obstacles();
+    let obstacles = obstacles.borrow();
+    let mut cover_circles: Vec<Circle> = Vec::new();
+    for obstacle in obstacles.iter() {
+      let obstacle_circle: Circle = obstacle.get_circle();
+      let obstacle_center: Point2DD = obstacle_circle.get_center_point_2dd();
+      let distance: f64 = threat_center.distance_to(&obstacle_center);
+      if distance <= 0. {
+        continue;
+      }
+      let offset: f64 = obstacle_circle.radius + tank_radius + A_STAR_STEP_SIZE;
+      let cover_center = Point2DD::new(
+        obstacle_center.x
+          + offset * (obstacle_center.x - threat_center.x) / distance,
+        obstacle_center.y
+          + offset * (obstacle_center.y - threat_center.y) / distance,
+      );
+      if !center_bounds.contains(&cover_center) {
+        continue;
+      }
+      let mut tank_cover_circle: Circle = *tank_circle;
+      tank_cover_circle.set_center_from_point_2dd(&cover_center);
+      if obstacles
+        .iter()
+        .any(|other| other.intersects_circle(&tank_cover_circle))
+      {
+        continue;
+      }
+      cover_circles.push(Circle {
+        center_x: cover_center.x,
+        center_y: cover_center.y,
+        radius: A_STAR_STEP_SIZE,
+      });
+    }
+    cover_circles.sort_by(|a, b| {
+      let distance_a: f64 = a.get_center_point_2dd().distance_to(&tank_center);
+      let distance_b: f64 = b.get_center_point_2dd().distance_to(&tank_center);
+      distance_a.total_cmp(&distance_b)
+    });
+    cover_circles
+  }
+
+  pub fn new(world: Weak<dyn World>) -> Self {
+    Self {
+      world,
+    }
+  }
+}
@@ -0,0 +1,143 @@
+// =============================================================================
+//! - Evader for CroftSoft Mars
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constant::TANK_REVERSE_DISTANCE_MAX;
+use crate::model::tank::TankAccessor;
+use crate::world::World;
+use com_croftsoft_core::math::geom::circle::{Circle, CircleAccessor};
+use com_croftsoft_core::math::geom::point_2dd::Point2DD;
+use core::f64::consts::{FRAC_PI_2, PI};
+use std::rc::Weak;
+
+// An incoming bullet that will hit the tank if the tank does not move
+struct Threat {
+  bullet_radius: f64,
+  heading: f64,
+  // Signed distance of the tank center from the line of fire
+  offset: f64,
+  time_to_impact: f64,
+}
+
+pub struct Evader {
+  world: Weak<dyn World>,
+}
+
+impl Evader {
+  // The incoming bullet that will arrive first
+  fn find_threat(
+    &self,
+    tank_circle: &Circle,
+  ) -> Option<Threat> {
+    let mut closest_threat: Option<Threat> = None;
+    let world = self.world.upgrade().unwrap();
+    for bullet in world.get_bullets().borrow().iter() {
+      if bullet.get_damage() <= 0. {
+        continue;
+      }
+      let heading: f64 = bullet.get_heading();
+      let bullet_circle: Circle = bullet.get_circle();
+      let clearance: f64 = tank_circle.radius + bullet_circle.radius;
+      let delta_x: f64 = tank_circle.center_x - bullet_circle.center_x;
+      let delta_y: f64 = tank_circle.center_y - bullet_circle.center_y;
+      let along: f64 = delta_x * heading.cos() + delta_y * heading.sin();
+      if along <= 0. || along - clearance > bullet.get_range_remaining() {
+        continue;
+      }
+      let offset: f64 = heading.cos() * delta_y - heading.sin() * delta_x;
+      if offset.abs() >= clearance {
+        continue;
+      }
+      let time_to_impact: f64 =
+        (along - clearance).max(0.) / bullet.get_velocity();
+      if let Some(threat) = &closest_threat {
+        if threat.time_to_impact <= time_to_impact {
+          continue;
+        }
+      }
+      closest_threat = Some(Threat {
+        bullet_radius: bullet_circle.radius,
+        heading,
+        offset,
+        time_to_impact,
+      });
+    }
+    closest_threat
+  }
+
+  // A destination out of the line of fire of the first incoming bullet.
+  // Of the two directions perpendicular to the line of fire it picks the one
+  // the tank can clear sooner given its rotation, driving forward or backing
+  // up a short distance.
+  pub fn find_sidestep(
+    &self,
+    tank: &dyn TankAccessor,
+  ) -> Option<Point2DD> {
+    let tank_circle: Circle = tank.get_circle();
+    let threat: Threat = self.find_threat(&tank_circle)?;
+    let clearance: f64 = tank_circle.radius + threat.bullet_radius;
+    let body_heading: f64 = tank.get_body_heading();
+    let velocity: f64 = tank.get_velocity();
+    let side: f64 = if threat.offset >= 0. {
+      1.
+    } else {
+      -1.
+    };
+    let candidates: [(f64, f64); 2] = [
+      // Away from the line of fire on the side the tank is already on
+      (
+        threat.heading + side * FRAC_PI_2,
+        clearance - threat.offset.abs(),
+      ),
+      // Across the line of fire to the other side
+      (
+        threat.heading - side * FRAC_PI_2,
+        clearance + threat.offset.abs(),
+      ),
+    ];
+    let (heading, distance, _) = candidates
+      .iter()
+      .map(|(heading, distance)| {
+        let mut time: f64 = tank.calculate_drive_time(
+          body_heading,
+          *heading,
+          velocity,
+          *distance,
+          false,
+        );
+        if *distance <= TANK_REVERSE_DISTANCE_MAX {
+          let reverse_time: f64 = tank.calculate_drive_time(
+            body_heading,
+            *heading + PI,
+            velocity,
+            *distance,
+            true,
+          );
+          time = time.min(reverse_time);
+        }
+        (*heading, *distance, time)
+      })
+      .min_by(|a, b| a.2.total_cmp(&b.2))?;
+    let distance: f64 = distance + threat.bullet_radius;
+    let center: Point2DD = tank_circle.get_center_point_2dd();
+    Some(Point2DD::new(
+      center.x + distance * heading.cos(),
+      center.y + distance * heading.sin(),
+    ))
+  }
+
+  pub fn new(world: Weak<dyn World>) -> Self {
+    Self {
+      world,
+    }
+  }
+}
@@ -0,0 +1,90 @@
+// =============================================================================
+//! - Explorer for CroftSoft Mars
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::ai::perception::Perception;
+use crate::constant::{EXPLORATION_CELL_SIZE, EXPLORATION_DISTANCE_WEIGHT};
+use crate::model::tank::{Color, TankAccessor};
+use crate::world::World;
+use com_croftsoft_core::math::geom::circle::{Circle, CircleAccessor};
+use com_croftsoft_core::math::geom::point_2dd::Point2DD;
+use std::rc::Weak;
+
+// Picks areas to scout when the team does not know where the enemy is
+pub struct Explorer {
+  world: Weak<dyn World>,
+}
+
+impl Explorer {
+  // The exploration grid cell the team has not seen for the longest time,
+  // discounted by the distance from the tank, where the tank would fit
+  pub fn find_exploration_circle(
+    &self,
+    tank: &dyn TankAccessor,
+  ) -> Option<Circle> {
+    let tank_center: Point2DD = tank.get_center();
+    let tank_radius: f64 = tank.get_radius();
+    let world = self.world.upgrade().unwrap();
+    let cell_staleness: Vec<f64> = world
+      .get_perception()
+      .borrow()
+      .get_cell_staleness(tank.get_color());
+    let mut best_circle: Option<Circle> = None;
+    let mut best_score: f64 = 0.;
+    for (index, staleness) in cell_staleness.iter().enumerate() {
+      let cell_center: Point2DD = Perception::get_cell_center(index);
+      let score: f64 = staleness
+        - EXPLORATION_DISTANCE_WEIGHT * tank_center.distance_to(&cell_center);
+      if score <= best_score {
+        continue;
+      }
+      let tank_circle = Circle {
+        center_x: cell_center.x,
+        center_y: cell_center.y,
+        radius: tank_radius,
+      };
+      if world.is_blocked_by_impassable(&tank_circle) {
+        continue;
+      }
+      best_score = score;
+      best_circle = Some(Circle {
+        center_x: cell_center.x,
+        center_y: cell_center.y,
+        radius: EXPLORATION_CELL_SIZE / 2.,
+      });
+    }
+    best_circle
+  }
+
+  // True if the team can currently see the cell at the center of the circle
+  pub fn is_explored(
+    &self,
+    color: Color,
+    circle: &Circle,
+  ) -> bool {
+    let Some(index) =
+      Perception::get_cell_index(&circle.get_center_point_2dd())
+    else {
+      return true;
+    };
+    let world = self.world.upgrade().unwrap();
+    let cell_staleness: Vec<f64> =
+      world.get_perception().borrow().get_cell_staleness(color);
+    cell_staleness[index] <= 0.
+  }
+
+  pub fn new(world: Weak<dyn World>) -> Self {
+    Self {
+      world,
+    }
+  }
+}
@@ -2,15 +2,23 @@
 //! - Artificial Intelligence (AI) module for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-06-03
-//! - Updated: 2023-06-03
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
+pub mod ai_profile;
+pub mod cover_finder;
+pub mod evader;
+pub mod explorer;
+pub mod perception;
+pub mod reservation_table;
 pub mod state_space_node;
 pub mod tank_cartographer;
 pub mod tank_operator;
+pub mod target_selector;
+pub mod weapon_selector;
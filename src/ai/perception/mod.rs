@@ -0,0 +1,299 @@
+// =============================================================================
+//! - Perception for CroftSoft Mars
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::ai::tank_operator::TankOperator;
+use crate::constant::{
+  BOUNDS, EXPLORATION_CELL_SIZE, EXPLORATION_STALENESS_INITIAL,
+  PERCEPTION_MEMORY_TIME_SECONDS, PERCEPTION_SEARCH_RADIUS,
+  PERCEPTION_SIGHT_RADIUS,
+};
+use crate::model::mine::Mine;
+use crate::model::obstacle::Obstacle;
+use crate::model::tank::Color;
+use com_croftsoft_core::math::geom::circle::{Circle, CircleAccessor};
+use com_croftsoft_core::math::geom::point_2dd::Point2DD;
+use std::collections::{HashMap, VecDeque};
+
+// An enemy tank as last seen by a team
+#[derive(Clone, Copy, Debug)]
+pub struct KnownEnemy {
+  // The range of the standard bullets of the enemy tank
+  pub bullet_range: f64,
+  pub circle: Circle,
+  pub damage: f64,
+  // The ID of the tank operator of the enemy tank
+  pub id: usize,
+  pub reloading: bool,
+  pub sparking: bool,
+  pub time_since_seen: f64,
+}
+
+// What each team knows about the enemy tanks, the enemy mines, and the
+// explored areas
+#[derive(Default)]
+pub struct Perception {
+  // Seconds since each cell of the coarse exploration grid was last seen
+  color_to_cell_staleness: HashMap<Color, Vec<f64>>,
+  color_to_known_enemies: HashMap<Color, Vec<KnownEnemy>>,
+  // Enemy mines seen by each team by mine ID
+  color_to_known_mines: HashMap<Color, HashMap<usize, Circle>>,
+}
+
+impl Perception {
+  // True if the point is within the sight radius of the viewer and no obstacle
+  // blocks the line of sight.  An obstacle containing the point does not block
+  // the view of that point.
+  pub fn can_see(
+    viewer: &Point2DD,
+    point: &Point2DD,
+    obstacles: &VecDeque<Box<dyn Obstacle>>,
+  ) -> bool {
+    let distance: f64 = viewer.distance_to(point);
+    if distance > PERCEPTION_SIGHT_RADIUS {
+      return false;
+    }
+    if distance <= 0. {
+      return true;
+    }
+    let unit_x: f64 = (point.x - viewer.x) / distance;
+    let unit_y: f64 = (point.y - viewer.y) / distance;
+    !obstacles.iter().any(|obstacle| {
+      let circle: Circle = obstacle.get_circle();
+      if circle.contains(point.x, point.y) {
+        return false;
+      }
+      let delta_x: f64 = circle.center_x - viewer.x;
+      let delta_y: f64 = circle.center_y - viewer.y;
+      let along: f64 =
+        (delta_x * unit_x + delta_y * unit_y).clamp(0., distance);
+      let closest =
+        Point2DD::new(viewer.x + along * unit_x, viewer.y + along * unit_y);
+      closest.distance_to(&circle.get_center_point_2dd()) < circle.radius
+    })
+  }
+
+  pub fn clear(&mut self) {
+    self.color_to_cell_staleness.clear();
+    self.color_to_known_enemies.clear();
+    self.color_to_known_mines.clear();
+  }
+
+  pub fn get_cell_center(index: usize) -> Point2DD {
+    let column: usize = index % Perception::get_column_count();
+    let row: usize = index / Perception::get_column_count();
+    Point2DD::new(
+      BOUNDS.x_min + (column as f64 + 0.5) * EXPLORATION_CELL_SIZE,
+      BOUNDS.y_min + (row as f64 + 0.5) * EXPLORATION_CELL_SIZE,
+    )
+  }
+
+  pub fn get_cell_count() -> usize {
+    Perception::get_column_count() * Perception::get_row_count()
+  }
+
+  pub fn get_cell_index(point: &Point2DD) -> Option<usize> {
+    if point.x < BOUNDS.x_min || point.y < BOUNDS.y_min {
+      return None;
+    }
+    let column = ((point.x - BOUNDS.x_min) / EXPLORATION_CELL_SIZE) as usize;
+    let row = ((point.y - BOUNDS.y_min) / EXPLORATION_CELL_SIZE) as usize;
+    if column >= Perception::get_column_count()
+      || row >= Perception::get_row_count()
+    {
+      return None;
+    }
+    Some(row * Perception::get_column_count() + column)
+  }
+
+  // Seconds since each cell was last seen by the team
+  pub fn get_cell_staleness(
+    &self,
+    color: Color,
+  ) -> Vec<f64> {
+    self
+      .color_to_cell_staleness
+      .get(&color)
+      .cloned()
+      .unwrap_or_else(|| {
+        vec![EXPLORATION_STALENESS_INITIAL; Perception::get_cell_count()]
+      })
+  }
+
+  fn get_column_count() -> usize {
+    ((BOUNDS.x_max - BOUNDS.x_min) / EXPLORATION_CELL_SIZE).ceil() as usize
+  }
+
+  pub fn get_known_enemies(
+    &self,
+    color: Color,
+  ) -> Vec<KnownEnemy> {
+    self
+      .color_to_known_enemies
+      .get(&color)
+      .cloned()
+      .unwrap_or_default()
+  }
+
+  pub fn get_known_enemy(
+    &self,
+    color: Color,
+    id: usize,
+  ) -> Option<KnownEnemy> {
+    self
+      .color_to_known_enemies
+      .get(&color)?
+      .iter()
+      .find(|known_enemy| known_enemy.id == id)
+      .copied()
+  }
+
+  pub fn get_known_mines(
+    &self,
+    color: Color,
+  ) -> Vec<Circle> {
+    self
+      .color_to_known_mines
+      .get(&color)
+      .map(|known_mines| known_mines.values().copied().collect())
+      .unwrap_or_default()
+  }
+
+  fn get_row_count() -> usize {
+    ((BOUNDS.y_max - BOUNDS.y_min) / EXPLORATION_CELL_SIZE).ceil() as usize
+  }
+
+  // Refreshes the cells, enemies, and mines each team can see and forgets the
+  // enemies that have not been seen for a while or that are no longer where
+  // they were last seen and the mines that have detonated
+  pub fn update(
+    &mut self,
+    mines: &VecDeque<Box<dyn Mine>>,
+    obstacles: &VecDeque<Box<dyn Obstacle>>,
+    tank_operators: &VecDeque<Box<dyn TankOperator>>,
+    time_delta: f64,
+  ) {
+    let mut color_to_viewers: HashMap<Color, Vec<Point2DD>> = HashMap::new();
+    // The color, burning flag, and current state of each active tank
+    let mut sightings: Vec<(Color, bool, KnownEnemy)> = Vec::new();
+    for tank_operator in tank_operators.iter() {
+      let tank = tank_operator.get_tank();
+      let tank = tank.borrow();
+      if !tank.is_active() {
+        continue;
+      }
+      let color: Color = tank.get_color();
+      let burning: bool = tank.is_burning();
+      if !burning {
+        color_to_viewers
+          .entry(color)
+          .or_default()
+          .push(tank.get_center());
+      }
+      sightings.push((
+        color,
+        burning,
+        KnownEnemy {
+          bullet_range: tank.get_archetype().bullet.range,
+          circle: tank.get_circle(),
+          damage: tank.get_damage(),
+          id: tank_operator.get_id(),
+          reloading: tank.is_reloading(),
+          sparking: tank.is_sparking(),
+          time_since_seen: 0.,
+        },
+      ));
+    }
+    self
+      .color_to_cell_staleness
+      .retain(|color, _| color_to_viewers.contains_key(color));
+    self
+      .color_to_known_enemies
+      .retain(|color, _| color_to_viewers.contains_key(color));
+    self
+      .color_to_known_mines
+      .retain(|color, _| color_to_viewers.contains_key(color));
+    for (color, viewers) in color_to_viewers.iter() {
+      let cell_staleness: &mut Vec<f64> = self
+        .color_to_cell_staleness
+        .entry(*color)
+        .or_insert_with(|| {
+          vec![EXPLORATION_STALENESS_INITIAL; Perception::get_cell_count()]
+        });
+      for (index, staleness) in cell_staleness.iter_mut().enumerate() {
+        let cell_center: Point2DD = Perception::get_cell_center(index);
+        if viewers
+          .iter()
+          .any(|viewer| Perception::can_see(viewer, &cell_center, obstacles))
+        {
+          *staleness = 0.;
+        } else {
+          *staleness += time_delta;
+        }
+      }
+      let known_mines: &mut HashMap<usize, Circle> =
+        self.color_to_known_mines.entry(*color).or_default();
+      known_mines.retain(|id, _| {
+        mines
+          .iter()
+          .any(|mine| mine.get_id() == *id && mine.is_active())
+      });
+      for mine in mines.iter() {
+        if mine.get_color() == *color || !mine.is_active() {
+          continue;
+        }
+        let mine_circle: Circle = mine.get_circle();
+        let mine_center: Point2DD = mine_circle.get_center_point_2dd();
+        if viewers
+          .iter()
+          .any(|viewer| Perception::can_see(viewer, &mine_center, obstacles))
+        {
+          known_mines.insert(mine.get_id(), mine_circle);
+        }
+      }
+      let known_enemies: &mut Vec<KnownEnemy> =
+        self.color_to_known_enemies.entry(*color).or_default();
+      for known_enemy in known_enemies.iter_mut() {
+        known_enemy.time_since_seen += time_delta;
+      }
+      for (enemy_color, burning, enemy) in sightings.iter() {
+        if enemy_color == color {
+          continue;
+        }
+        let enemy_center: Point2DD = enemy.circle.get_center_point_2dd();
+        if !viewers
+          .iter()
+          .any(|viewer| Perception::can_see(viewer, &enemy_center, obstacles))
+        {
+          continue;
+        }
+        known_enemies.retain(|known_enemy| known_enemy.id != enemy.id);
+        if !burning {
+          known_enemies.push(*enemy);
+        }
+      }
+      known_enemies.retain(|known_enemy| {
+        if known_enemy.time_since_seen <= 0. {
+          return true;
+        }
+        if known_enemy.time_since_seen > PERCEPTION_MEMORY_TIME_SECONDS {
+          return false;
+        }
+        let last_seen: Point2DD = known_enemy.circle.get_center_point_2dd();
+        !viewers.iter().any(|viewer| {
+          viewer.distance_to(&last_seen) <= PERCEPTION_SEARCH_RADIUS
+            && Perception::can_see(viewer, &last_seen, obstacles)
+        })
+      });
+    }
+  }
+}
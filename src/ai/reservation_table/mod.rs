@@ -0,0 +1,112 @@
+// =============================================================================
+//! - Reservation Table for CroftSoft Mars
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constant::A_STAR_RESERVATION_TIME_WINDOW;
+use crate::model::tank::Color;
+use com_croftsoft_core::math::geom::circle::{Circle, CircleAccessor};
+use std::collections::HashMap;
+
+// Space that a tank plans to occupy at a time relative to when it planned
+#[derive(Clone, Copy, Debug)]
+pub struct Reservation {
+  pub circle: Circle,
+  pub time: f64,
+}
+
+struct ReservationEntry {
+  color: Color,
+  reservations: Vec<Reservation>,
+  stalled: bool,
+}
+
+// Space-time reservations shared by the tank operators so that friendly tanks
+// plan paths around each other. Tanks with lower IDs have priority.
+#[derive(Default)]
+pub struct ReservationTable {
+  tank_id_to_entry: HashMap<usize, ReservationEntry>,
+}
+
+impl ReservationTable {
+  pub fn clear(&mut self) {
+    self.tank_id_to_entry.clear();
+  }
+
+  // Whether a friendly tank with priority has reserved the space and time
+  pub fn is_reserved(
+    &self,
+    circle: &Circle,
+    color: Color,
+    tank_id: usize,
+    time: f64,
+  ) -> bool {
+    self.tank_id_to_entry.iter().any(|(other_tank_id, entry)| {
+      *other_tank_id < tank_id
+        && entry.color == color
+        && entry.reservations.iter().any(|reservation| {
+          (reservation.time - time).abs() <= A_STAR_RESERVATION_TIME_WINDOW
+            && reservation.circle.intersects_circle(circle)
+        })
+    })
+  }
+
+  pub fn is_stalled(
+    &self,
+    tank_id: usize,
+  ) -> bool {
+    self
+      .tank_id_to_entry
+      .get(&tank_id)
+      .map_or(false, |entry| entry.stalled)
+  }
+
+  pub fn release(
+    &mut self,
+    tank_id: usize,
+  ) {
+    self.tank_id_to_entry.remove(&tank_id);
+  }
+
+  pub fn reserve(
+    &mut self,
+    color: Color,
+    reservations: Vec<Reservation>,
+    tank_id: usize,
+  ) {
+    let stalled: bool = self.is_stalled(tank_id);
+    self.tank_id_to_entry.insert(
+      tank_id,
+      ReservationEntry {
+        color,
+        reservations,
+        stalled,
+      },
+    );
+  }
+
+  pub fn set_stalled(
+    &mut self,
+    color: Color,
+    stalled: bool,
+    tank_id: usize,
+  ) {
+    self
+      .tank_id_to_entry
+      .entry(tank_id)
+      .or_insert_with(|| ReservationEntry {
+        color,
+        reservations: Vec::new(),
+        stalled,
+      })
+      .stalled = stalled;
+  }
+}
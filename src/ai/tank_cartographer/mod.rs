@@ -2,29 +2,44 @@
 //! - Tank Cartographer for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-04-07
-//! - Updated: 2023-08-03
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
+use super::perception::KnownEnemy;
+use super::reservation_table::Reservation;
 use super::state_space_node::StateSpaceNode;
+use crate::constant::{
+  AMMO_DUMP_EXPLOSION_FACTOR, A_STAR_AMMO_DUMP_HAZARD_WEIGHT,
+  A_STAR_EXPOSURE_WEIGHT, A_STAR_OBSTACLE_PREDICTION_TIME_MAX,
+  TANK_REVERSE_DISTANCE_MAX,
+};
 use crate::model::tank::Tank;
+use crate::world::terrain::TerrainKind;
 use crate::world::World;
 use com_croftsoft_core::ai::astar::traits::Cartographer;
 use com_croftsoft_core::math::geom::circle::{Circle, CircleAccessor};
 use com_croftsoft_core::math::geom::point_2dd::Point2DD;
 use com_croftsoft_core::math::geom::point_xy::PointXY;
 use std::cell::{Ref, RefCell};
-use std::f64::consts::PI;
+use std::collections::{HashMap, VecDeque};
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
 use std::rc::{Rc, Weak};
 
 pub struct TankCartographer {
   adjacent_nodes: RefCell<Vec<StateSpaceNode>>,
+  ammo_dump_hazard_weight: f64,
+  // Estimated time from the start for the tank to reach each node
+  arrival_times: RefCell<HashMap<StateSpaceNode, f64>>,
   directions: usize,
+  // Space that a stuck tank should plan around
+  excluded_circle: Option<Circle>,
+  exposure_weight: f64,
   goal_circle: Circle,
   goal_state_space_node: StateSpaceNode,
   id: usize,
@@ -35,25 +50,108 @@ pub struct TankCartographer {
 }
 
 impl TankCartographer {
-  fn calculate_travel_time(
+  // Time to rotate toward and then drive to the adjacent node, backing up if
+  // the heading of the adjacent node faces away from the direction of travel.
+  // The tank is assumed to leave any node other than the start at top speed.
+  fn calculate_time(
     &self,
     from_node: &StateSpaceNode,
     to_node: &StateSpaceNode,
   ) -> f64 {
-    let distance: f64 = from_node.distance(to_node);
-    let tank_speed: f64 =
-      self.tank.upgrade().unwrap().borrow().get_tank_speed();
-    distance / tank_speed
+    let tank = self.tank.upgrade().unwrap();
+    let tank = tank.borrow();
+    let velocity: f64 = if *from_node == self.start_state_space_node {
+      tank.get_velocity()
+    } else {
+      tank.get_tank_speed()
+    };
+    tank.calculate_drive_time(
+      from_node.get_heading(),
+      to_node.get_heading(),
+      velocity,
+      from_node.distance(to_node),
+      TankCartographer::is_reversing(from_node, to_node),
+    )
+  }
+
+  // Number of nominal ammo dumps that could blow up on top of the node
+  fn count_ammo_dump_hazards(
+    &self,
+    node: &StateSpaceNode,
+  ) -> usize {
+    let point_xy: Point2DD = node.get_point_xy();
+    let world: Rc<dyn World> = self.world.upgrade().unwrap();
+    let ammo_dumps = world.get_ammo_dumps();
+    let ammo_dumps = ammo_dumps.borrow();
+    ammo_dumps
+      .iter()
+      .filter(|ammo_dump| {
+        if !ammo_dump.is_nominal() {
+          return false;
+        }
+        let ammo_dump_circle: Circle = ammo_dump.get_circle();
+        // The ammo dump the tank is heading for is not a hazard
+        if self.goal_circle.intersects_circle(&ammo_dump_circle) {
+          return false;
+        }
+        let explosion_radius: f64 =
+          AMMO_DUMP_EXPLOSION_FACTOR * ammo_dump.get_ammo();
+        ammo_dump_circle
+          .get_center_point_2dd()
+          .distance_to(&point_xy)
+          <= explosion_radius + self.get_tank_radius()
+      })
+      .count()
+  }
+
+  // Number of enemy turrets known to the team, where last seen, that have the
+  // node within bullet range
+  fn count_enemy_turrets_in_range(
+    &self,
+    node: &StateSpaceNode,
+  ) -> usize {
+    let point_xy: Point2DD = node.get_point_xy();
+    let tank_color = self.tank.upgrade().unwrap().borrow().get_color();
+    let world: Rc<dyn World> = self.world.upgrade().unwrap();
+    let known_enemies: Vec<KnownEnemy> = world
+      .get_perception()
+      .borrow()
+      .get_known_enemies(tank_color);
+    known_enemies
+      .iter()
+      .filter(|known_enemy| {
+        known_enemy
+          .circle
+          .get_center_point_2dd()
+          .distance_to(&point_xy)
+          <= known_enemy.bullet_range + known_enemy.circle.radius
+      })
+      .count()
+  }
+
+  fn get_arrival_time(
+    &self,
+    node: &StateSpaceNode,
+  ) -> f64 {
+    if let Some(arrival_time) = self.arrival_times.borrow().get(node) {
+      return *arrival_time;
+    }
+    self.calculate_time(&self.start_state_space_node, node)
   }
 
   pub fn get_id(&self) -> usize {
     self.id
   }
 
+  fn get_tank_radius(&self) -> f64 {
+    self.tank.upgrade().unwrap().borrow().get_radius()
+  }
+
   fn is_space_available(
     &self,
     x: f64,
     y: f64,
+    arrival_time: f64,
   ) -> bool {
     let tank: Rc<RefCell<dyn Tank>> = self.tank.upgrade().unwrap();
     let tank: Ref<dyn Tank> = tank.borrow();
@@ -94,10 +192,43 @@ impl TankCartographer {
         return false;
       }
     }
+    // Space reserved by friendly tanks with priority is impassable
+    if self
+      .world
+      .upgrade()
+      .unwrap()
+      .get_reservation_table()
+      .borrow()
+      .is_reserved(&tank_circle, tank_color, self.id, arrival_time)
+    {
+      return false;
+    }
+    if let Some(excluded_circle) = self.excluded_circle {
+      if excluded_circle.intersects_circle(&tank_circle) {
+        return false;
+      }
+    }
+    // Enemy mines the team knows about are impassable
+    if self
+      .world
+      .upgrade()
+      .unwrap()
+      .get_perception()
+      .borrow()
+      .get_known_mines(tank_color)
+      .iter()
+      .any(|mine_circle| mine_circle.intersects_circle(&tank_circle))
+    {
+      return false;
+    }
     // If the tank is within the goal circle, obstacles are no obstacle
     if self.goal_circle.intersects_circle(&tank_circle) {
       return true;
     }
+    // Drifting obstacles are checked where they are predicted to be when the
+    // tank arrives, up to a time limit after which the prediction is unreliable
+    let prediction_time: f64 =
+      arrival_time.clamp(0., A_STAR_OBSTACLE_PREDICTION_TIME_MAX);
     // TODO: previously operated on an array of Impassable
     for obstacle in self
       .world
@@ -107,7 +238,10 @@ impl TankCartographer {
       .borrow()
       .iter()
     {
-      if obstacle.get_circle().intersects_circle(&tank_circle) {
+      let mut obstacle_circle: Circle = obstacle.get_circle();
+      obstacle_circle.center_x += prediction_time * obstacle.get_velocity_x();
+      obstacle_circle.center_y += prediction_time * obstacle.get_velocity_y();
+      if obstacle_circle.intersects_circle(&tank_circle) {
         return false;
       }
     }
@@ -126,7 +260,11 @@ impl TankCartographer {
     let start_state_space_node = StateSpaceNode::new(0., Point2DD::default());
     TankCartographer {
       adjacent_nodes: RefCell::new(Vec::new()),
+      ammo_dump_hazard_weight: A_STAR_AMMO_DUMP_HAZARD_WEIGHT,
+      arrival_times: RefCell::new(HashMap::new()),
       directions,
+      excluded_circle: None,
+      exposure_weight: A_STAR_EXPOSURE_WEIGHT,
       goal_circle,
       goal_state_space_node,
       id,
@@ -137,6 +275,22 @@ impl TankCartographer {
     }
   }
 
+  fn is_reversing(
+    from_node: &StateSpaceNode,
+    to_node: &StateSpaceNode,
+  ) -> bool {
+    let from_point: Point2DD = from_node.get_point_xy();
+    let to_point: Point2DD = to_node.get_point_xy();
+    if from_point == to_point {
+      return false;
+    }
+    let travel_heading: f64 =
+      (to_point.y - from_point.y).atan2(to_point.x - from_point.x);
+    let heading_delta: f64 =
+      (to_node.get_heading() - travel_heading).rem_euclid(TAU);
+    heading_delta > FRAC_PI_2 && heading_delta < PI + FRAC_PI_2
+  }
+
   pub fn push_adjacent_node(
     &self,
     adjacent_node: &StateSpaceNode,
@@ -152,8 +306,69 @@ impl TankCartographer {
     true
   }
 
+  pub fn release_path(&self) {
+    self
+      .world
+      .upgrade()
+      .unwrap()
+      .get_reservation_table()
+      .borrow_mut()
+      .release(self.id);
+  }
+
+  // Shares the planned path with friendly tank operators
+  pub fn reserve_path(
+    &self,
+    path: &VecDeque<StateSpaceNode>,
+  ) {
+    let tank: Rc<RefCell<dyn Tank>> = self.tank.upgrade().unwrap();
+    let tank: Ref<dyn Tank> = tank.borrow();
+    let mut circle: Circle = tank.get_circle();
+    let reservations: Vec<Reservation> = path
+      .iter()
+      .map(|node| {
+        circle.set_center_from_point_2dd(&node.get_point_xy());
+        Reservation {
+          circle,
+          time: self.get_arrival_time(node),
+        }
+      })
+      .collect();
+    self
+      .world
+      .upgrade()
+      .unwrap()
+      .get_reservation_table()
+      .borrow_mut()
+      .reserve(tank.get_color(), reservations, self.id);
+  }
+
   pub fn reset(&self) {
     self.adjacent_nodes.borrow_mut().clear();
+    self.arrival_times.borrow_mut().clear();
+  }
+
+  // A weight of zero disables the ammo dump hazard cost term
+  pub fn set_ammo_dump_hazard_weight(
+    &mut self,
+    ammo_dump_hazard_weight: f64,
+  ) {
+    self.ammo_dump_hazard_weight = ammo_dump_hazard_weight;
+  }
+
+  pub fn set_excluded_circle(
+    &mut self,
+    excluded_circle: Option<Circle>,
+  ) {
+    self.excluded_circle = excluded_circle;
+  }
+
+  // A weight of zero disables the enemy turret exposure cost term
+  pub fn set_exposure_weight(
+    &mut self,
+    exposure_weight: f64,
+  ) {
+    self.exposure_weight = exposure_weight;
   }
 
   pub fn set_goal_circle(
@@ -178,7 +393,10 @@ impl Cartographer<StateSpaceNode> for TankCartographer {
     &self,
     node: &StateSpaceNode,
   ) -> f64 {
-    self.get_cost_to_adjacent_node(node, &self.goal_state_space_node)
+    // Threat costs are excluded and the fastest terrain is assumed so that
+    // the estimate is never too high
+    self.calculate_time(node, &self.goal_state_space_node)
+      / TerrainKind::SPEED_MULTIPLIER_MAX
   }
 
   fn get_adjacent_nodes(
@@ -203,23 +421,48 @@ impl Cartographer<StateSpaceNode> for TankCartographer {
     let x: f64 = point_xy.get_x();
     let y: f64 = point_xy.get_y();
     let goal_point_xy = self.goal_state_space_node.get_point_xy();
+    let node_arrival_time: f64 = self.get_arrival_time(node);
     // TODO: check if atan2 arguments reversed
     let heading_to_goal =
       (goal_point_xy.get_y() - y).atan2(goal_point_xy.get_x() - x);
+    // The tank only backs up to nearby destinations so reversing is only
+    // considered for the first step, the same way the tank decides
+    let reversible: bool = *node == self.start_state_space_node
+      && step_size <= TANK_REVERSE_DISTANCE_MAX;
     for i in 0..self.directions {
       let heading: f64 =
         heading_to_goal + (i as f64) * 2. * PI / (self.directions as f64);
-      let adjacent_state_space_node: StateSpaceNode = StateSpaceNode::new(
+      let mut adjacent_state_space_node: StateSpaceNode = StateSpaceNode::new(
         heading,
         Point2DD::new(
           x + step_size * heading.cos(),
           y + step_size * heading.sin(),
         ),
       );
+      if reversible {
+        let reversed_state_space_node = StateSpaceNode::new(
+          (heading + PI).rem_euclid(TAU),
+          adjacent_state_space_node.get_point_xy(),
+        );
+        if self.calculate_time(node, &reversed_state_space_node)
+          < self.calculate_time(node, &adjacent_state_space_node)
+        {
+          adjacent_state_space_node = reversed_state_space_node;
+        }
+      }
       let point_xy = adjacent_state_space_node.get_point_xy();
-      if self.is_space_available(point_xy.get_x(), point_xy.get_y())
-        && self.push_adjacent_node(&adjacent_state_space_node)
+      let arrival_time: f64 = node_arrival_time
+        + self.calculate_time(node, &adjacent_state_space_node);
+      if self.is_space_available(
+        point_xy.get_x(),
+        point_xy.get_y(),
+        arrival_time,
+      ) && self.push_adjacent_node(&adjacent_state_space_node)
       {
+        self
+          .arrival_times
+          .borrow_mut()
+          .insert(adjacent_state_space_node, arrival_time);
         adjacent_list.push(adjacent_state_space_node);
       }
     }
@@ -231,18 +474,28 @@ impl Cartographer<StateSpaceNode> for TankCartographer {
     from_node: &StateSpaceNode,
     to_node: &StateSpaceNode,
   ) -> f64 {
-    let mut rotation: f64 = from_node.rotation(to_node);
-    rotation = rotation.abs();
-    let body_rotation_speed: f64 = self
-      .tank
+    let mut total_time: f64 = self.calculate_time(from_node, to_node);
+    // Extra time on slow terrain or time saved on roads
+    let terrain_speed_multiplier: f64 = self
+      .world
       .upgrade()
       .unwrap()
+      .get_terrain()
       .borrow()
-      .get_body_rotation_speed();
-    let rotation_time: f64 = rotation / body_rotation_speed;
-    let travel_time: f64 = self.calculate_travel_time(from_node, to_node);
-    let total_time: f64 = travel_time + rotation_time;
-    total_time
+      .get_speed_multiplier(&to_node.get_point_xy());
+    total_time /= terrain_speed_multiplier;
+    let mut threat_cost: f64 = 0.;
+    if self.exposure_weight > 0. {
+      let exposure_time: f64 =
+        total_time * self.count_enemy_turrets_in_range(to_node) as f64;
+      threat_cost += self.exposure_weight * exposure_time;
+    }
+    if self.ammo_dump_hazard_weight > 0. {
+      let hazard_time: f64 =
+        total_time * self.count_ammo_dump_hazards(to_node) as f64;
+      threat_cost += self.ammo_dump_hazard_weight * hazard_time;
+    }
+    total_time + threat_cost
   }
 
   fn is_goal_node(
@@ -2,23 +2,34 @@
 //! - Default Tank Operater for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-04-06
-//! - Updated: 2023-08-14
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
+use crate::ai::ai_profile::AiProfile;
+use crate::ai::cover_finder::CoverFinder;
+use crate::ai::evader::Evader;
+use crate::ai::explorer::Explorer;
 use crate::ai::state_space_node::StateSpaceNode;
 use crate::ai::tank_cartographer::TankCartographer;
 use crate::ai::tank_operator::TankOperator;
+use crate::ai::target_selector::{Target, TargetSelector};
+use crate::ai::weapon_selector::WeaponSelector;
 use crate::constant::{
-  A_STAR_DIRECTIONS, A_STAR_LOOPS, A_STAR_STEP_SIZE, TANK_DRIFT_PROBABILITY,
-  TANK_FIRING_PROBABILITY,
+  A_STAR_DIRECTIONS, A_STAR_STEP_SIZE, COVER_CANDIDATE_COUNT_MAXIMUM,
+  TANK_DAMAGE_MAX, TANK_DEADLOCK_TIME_SECONDS, TANK_DRIFT_PROBABILITY,
+  TANK_FIRING_PROBABILITY, TANK_MINE_LAYING_PROBABILITY,
+  TANK_RECOVERY_EXCLUSION_TIME_SECONDS, TANK_RECOVERY_TIME_SECONDS,
+  TANK_RETREAT_DAMAGE_FRACTION, TANK_STALLED_DISTANCE_MAX,
+  TANK_YIELD_TIME_SECONDS,
 };
-use crate::model::tank::Tank;
+use crate::model::bullet::BulletKind;
+use crate::model::tank::{Color, Tank, TankPart};
 use crate::world::World;
 use com_croftsoft_core::ai::astar::structures::AStar;
 use com_croftsoft_core::math::geom::circle::{Circle, CircleAccessor};
@@ -32,17 +43,195 @@ use std::rc::{Rc, Weak};
 
 pub struct DefaultTankOperator {
   a_star: AStar<StateSpaceNode>,
+  ai_profile: AiProfile,
+  aim_offset: f64,
+  back_up_time_remaining: f64,
   center: Point2DD,
+  cover_circle: Option<Circle>,
+  cover_finder: CoverFinder,
   destination: Point2DD,
+  evader: Evader,
+  exclusion_time_remaining: f64,
+  exploration_circle: Option<Circle>,
+  explorer: Explorer,
   id: usize,
+  previous_body_heading: f64,
+  previous_center: Point2DD,
+  reaction_time: f64,
+  stalled_time: f64,
   start_state_space_node: StateSpaceNode,
   tank: Rc<RefCell<dyn Tank>>,
   tank_cartographer: TankCartographer,
-  target_circle: Option<Circle>,
+  target_id: Option<usize>,
+  target_selector: TargetSelector,
+  threat_circle: Option<Circle>,
+  weapon_selector: WeaponSelector,
   world: Weak<dyn World>,
 }
 
 impl DefaultTankOperator {
+  // The target point rotated about the tank center by the aim offset
+  fn aim_at(
+    &self,
+    target_point: &Point2DD,
+  ) -> Point2DD {
+    let delta_x: f64 = target_point.x - self.center.x;
+    let delta_y: f64 = target_point.y - self.center.y;
+    let cos: f64 = self.aim_offset.cos();
+    let sin: f64 = self.aim_offset.sin();
+    Point2DD::new(
+      self.center.x + delta_x * cos - delta_y * sin,
+      self.center.y + delta_x * sin + delta_y * cos,
+    )
+  }
+
+  // Yields to a stalled friendly tank with priority that is blocking this
+  // stalled tank by backing away from it
+  fn detect_deadlock(
+    &mut self,
+    time_delta: f64,
+  ) {
+    let tank: Rc<RefCell<dyn Tank>> = self.tank.clone();
+    let tank = tank.borrow();
+    let center: Point2DD = tank.get_center();
+    let body_heading: f64 = tank.get_body_heading();
+    if center.distance_to(&self.previous_center) < TANK_STALLED_DISTANCE_MAX
+      && body_heading == self.previous_body_heading
+    {
+      self.stalled_time += time_delta;
+    } else {
+      self.stalled_time = 0.;
+    }
+    self.previous_body_heading = body_heading;
+    self.previous_center = center;
+    let stalled: bool = self.stalled_time >= TANK_DEADLOCK_TIME_SECONDS;
+    let world: Rc<dyn World> = self.world.upgrade().unwrap();
+    let reservation_table = world.get_reservation_table();
+    let color = tank.get_color();
+    reservation_table
+      .borrow_mut()
+      .set_stalled(color, stalled, self.id);
+    if !stalled {
+      return;
+    }
+    let mut contact_circle: Circle = tank.get_circle();
+    contact_circle.radius += A_STAR_STEP_SIZE;
+    let blocker_center_option: Option<Point2DD> = world
+      .get_tank_operators()
+      .borrow()
+      .iter()
+      .filter(|other_tank_operator| {
+        other_tank_operator.get_id() < self.id
+          && reservation_table
+            .borrow()
+            .is_stalled(other_tank_operator.get_id())
+      })
+      .map(|other_tank_operator| other_tank_operator.get_tank())
+      .find(|other_tank| {
+        let other_tank = other_tank.borrow();
+        other_tank.is_active()
+          && !other_tank.is_burning()
+          && other_tank.get_color() == color
+          && other_tank.intersects_circle(&contact_circle)
+      })
+      .map(|other_tank| other_tank.borrow().get_center());
+    let Some(blocker_center) = blocker_center_option else {
+      return;
+    };
+    self.set_destination_away_from(&center, &blocker_center);
+    self.stalled_time = 0.;
+    self.back_up_time_remaining = TANK_YIELD_TIME_SECONDS;
+    self.tank_cartographer.release_path();
+  }
+
+  // Backs away from whatever is blocking the stuck tank and then replans with
+  // the blocker excluded for a while
+  fn detect_stuck(
+    &mut self,
+    time_delta: f64,
+  ) {
+    if self.exclusion_time_remaining > 0. {
+      self.exclusion_time_remaining -= time_delta;
+      if self.exclusion_time_remaining <= 0. {
+        self.tank_cartographer.set_excluded_circle(None);
+      }
+    }
+    let tank: Rc<RefCell<dyn Tank>> = self.tank.clone();
+    let tank = tank.borrow();
+    if !tank.is_stuck() {
+      return;
+    }
+    let center: Point2DD = tank.get_center();
+    let mut contact_circle: Circle = tank.get_circle();
+    contact_circle.radius += A_STAR_STEP_SIZE;
+    let blocker_circle_option: Option<Circle> =
+      self.find_blocker_circle(&contact_circle);
+    if let Some(blocker_circle) = blocker_circle_option {
+      self.set_destination_away_from(
+        &center,
+        &blocker_circle.get_center_point_2dd(),
+      );
+    } else {
+      let body_heading: f64 = tank.get_body_heading();
+      let ahead = Point2DD::new(
+        center.x + body_heading.cos(),
+        center.y + body_heading.sin(),
+      );
+      self.set_destination_away_from(&center, &ahead);
+    }
+    self
+      .tank_cartographer
+      .set_excluded_circle(blocker_circle_option);
+    self.exclusion_time_remaining = TANK_RECOVERY_EXCLUSION_TIME_SECONDS;
+    self.back_up_time_remaining = TANK_RECOVERY_TIME_SECONDS;
+    self.tank_cartographer.release_path();
+  }
+
+  // Keeps scouting the same area until the team can see it
+  fn find_exploration_circle(&mut self) -> Option<Circle> {
+    let tank: Rc<RefCell<dyn Tank>> = self.tank.clone();
+    let tank = tank.borrow();
+    if let Some(exploration_circle) = self.exploration_circle {
+      if !self
+        .explorer
+        .is_explored(tank.get_color(), &exploration_circle)
+      {
+        return self.exploration_circle;
+      }
+    }
+    self.exploration_circle = self.explorer.find_exploration_circle(&*tank);
+    self.exploration_circle
+  }
+
+  // The closest obstacle or other tank in contact with the circle
+  fn find_blocker_circle(
+    &self,
+    contact_circle: &Circle,
+  ) -> Option<Circle> {
+    let world: Rc<dyn World> = self.world.upgrade().unwrap();
+    let mut blocker_circles: Vec<Circle> = world
+      .get_obstacles()
+      .borrow()
+      .iter()
+      .filter(|obstacle| obstacle.intersects_circle(contact_circle))
+      .map(|obstacle| obstacle.get_circle())
+      .collect();
+    for tank_operator in world.get_tank_operators().borrow().iter() {
+      let other_tank = tank_operator.get_tank();
+      let other_tank = other_tank.borrow();
+      if other_tank.is_active() && other_tank.intersects_circle(contact_circle)
+      {
+        blocker_circles.push(other_tank.get_circle());
+      }
+    }
+    let center: Point2DD = contact_circle.get_center_point_2dd();
+    blocker_circles.into_iter().min_by(|a, b| {
+      let distance_a: f64 = a.get_center_point_2dd().distance_to(&center);
+      let distance_b: f64 = b.get_center_point_2dd().distance_to(&center);
+      distance_a.total_cmp(&distance_b)
+    })
+  }
+
   fn get_first_step(
     &mut self,
     destination: Circle,
@@ -57,11 +246,12 @@ impl DefaultTankOperator {
       .tank_cartographer
       .set_start_state_space_node(self.start_state_space_node);
     self.tank_cartographer.set_goal_circle(destination);
-    for _ in 0..A_STAR_LOOPS {
+    for _ in 0..self.ai_profile.planning_budget {
       if !self.a_star.loop_once(&self.tank_cartographer) {
         break;
       }
     }
+    self.tank_cartographer.reserve_path(&self.a_star.get_path());
     if !self.a_star.is_goal_found() {
       return destination.get_center_point_2dd();
     }
@@ -72,32 +262,135 @@ impl DefaultTankOperator {
     }
   }
 
+  // Switches to a newly selected target only after the reaction delay
+  fn react(
+    &mut self,
+    color: Color,
+    selected_target: Option<Target>,
+    time_delta: f64,
+  ) -> Option<Target> {
+    let selected_target_id: Option<usize> =
+      selected_target.as_ref().map(|target| target.id);
+    if selected_target_id == self.target_id {
+      self.reaction_time = 0.;
+      return selected_target;
+    }
+    self.reaction_time += time_delta;
+    if self.reaction_time >= self.ai_profile.reaction_delay {
+      self.reaction_time = 0.;
+      self.sample_aim_offset();
+      return selected_target;
+    }
+    self
+      .target_id
+      .and_then(|target_id| self.target_selector.find_target(color, target_id))
+  }
+
+  fn sample_aim_offset(&mut self) {
+    let accuracy_jitter: f64 = self.ai_profile.accuracy_jitter;
+    let uniform = Uniform::from(-accuracy_jitter..=accuracy_jitter);
+    self.aim_offset = uniform.sample(&mut rand::thread_rng());
+  }
+
+  // First step toward the closest reachable cover from the threat
+  fn seek_cover(
+    &mut self,
+    threat_circle: Circle,
+    heading: f64,
+  ) -> Option<Point2DD> {
+    // Keeps heading for the cover already chosen while it remains reachable
+    if let Some(cover_circle) = self.cover_circle {
+      let destination: Point2DD = self.get_first_step(cover_circle, heading);
+      if self.a_star.is_goal_found() {
+        return Some(destination);
+      }
+      self.cover_circle = None;
+    }
+    let tank_circle: Circle = self.tank.borrow().get_circle();
+    let cover_circles: Vec<Circle> = self
+      .cover_finder
+      .find_cover_circles(&tank_circle, &threat_circle.get_center_point_2dd());
+    for cover_circle in cover_circles
+      .into_iter()
+      .take(COVER_CANDIDATE_COUNT_MAXIMUM)
+    {
+      let destination: Point2DD = self.get_first_step(cover_circle, heading);
+      if self.a_star.is_goal_found() {
+        self.cover_circle = Some(cover_circle);
+        return Some(destination);
+      }
+    }
+    None
+  }
+
+  fn set_destination_away_from(
+    &mut self,
+    center: &Point2DD,
+    point: &Point2DD,
+  ) {
+    let delta_x: f64 = center.x - point.x;
+    let delta_y: f64 = center.y - point.y;
+    let distance: f64 = center.distance_to(point).max(1.);
+    self.destination.set_xy(
+      center.x + A_STAR_STEP_SIZE * delta_x / distance,
+      center.y + A_STAR_STEP_SIZE * delta_y / distance,
+    );
+  }
+
   pub fn new(
+    ai_profile: AiProfile,
     id: usize,
     tank: Rc<RefCell<dyn Tank>>,
     world: Weak<dyn World>,
   ) -> Self {
-    let tank_cartographer = TankCartographer::new(
+    let cover_finder = CoverFinder::new(world.clone());
+    let evader = Evader::new(world.clone());
+    let explorer = Explorer::new(world.clone());
+    let mut tank_cartographer = TankCartographer::new(
       id,
       A_STAR_STEP_SIZE,
       A_STAR_DIRECTIONS,
       Rc::downgrade(&tank),
       world.clone(),
     );
+    tank_cartographer
+      .set_ammo_dump_hazard_weight(ai_profile.ammo_dump_hazard_weight);
+    tank_cartographer.set_exposure_weight(ai_profile.exposure_weight);
+    tank
+      .borrow_mut()
+      .set_turret_idle_mode(ai_profile.turret_idle_mode);
     let a_star = AStar::<StateSpaceNode>::default();
     let center = Point2DD::default();
     let destination = Point2DD::default();
-    let target_circle = None;
+    let mut target_selector = TargetSelector::new(world.clone());
+    target_selector.set_target_scorer(Box::new(ai_profile.target_scorer));
     let start_state_space_node = StateSpaceNode::default();
+    let weapon_selector = WeaponSelector::new(world.clone());
     Self {
       a_star,
+      ai_profile,
+      aim_offset: 0.,
+      back_up_time_remaining: 0.,
       center,
+      cover_circle: None,
+      cover_finder,
       destination,
+      evader,
+      exclusion_time_remaining: 0.,
+      exploration_circle: None,
+      explorer,
       id,
+      previous_body_heading: 0.,
+      previous_center: Point2DD::default(),
+      reaction_time: 0.,
+      stalled_time: 0.,
       start_state_space_node,
       tank_cartographer,
       tank,
-      target_circle,
+      target_id: None,
+      target_selector,
+      threat_circle: None,
+      weapon_selector,
       world,
     }
   }
@@ -129,36 +422,54 @@ impl TankOperator for DefaultTankOperator {
     self.tank.clone()
   }
 
+  fn get_target_id(&self) -> Option<usize> {
+    self.target_id
+  }
+
   fn update(
     &mut self,
     time_delta: f64,
   ) {
     let tank: Rc<RefCell<dyn Tank>> = self.tank.clone();
     {
-      // Rotate turret toward nearest enemy tank
+      // Rotate turret toward the selected known enemy tank
       let mut tank: RefMut<dyn Tank> = tank.borrow_mut();
       self.center = tank.get_center();
-      self.target_circle = tank.get_closest_enemy_tank_circle(
-        self.world.upgrade().unwrap().get_tank_operators(),
-      );
-      if self.target_circle.is_none() {
-        // Rotate turret toward nearest obstacle
-        self.target_circle = self
-          .world
-          .upgrade()
-          .unwrap()
-          .get_closest_obstacle_circle(&self.center);
-      }
-      let target_point: Option<Point2DD> = self
-        .target_circle
-        .as_ref()
-        .map(|target_circle| target_circle.get_center_point_2dd());
+      let selected_target_option: Option<Target> =
+        self.target_selector.select_target(&*tank, self.id);
+      let target_option: Option<Target> =
+        self.react(tank.get_color(), selected_target_option, time_delta);
+      self.target_id = target_option.as_ref().map(|target| target.id);
+      self.threat_circle = target_option.map(|target| target.circle);
+      // Without a threat the turret idles per the AI profile
+      let target_point: Option<Point2DD> =
+        self.threat_circle.as_ref().map(|threat_circle| {
+          self.aim_at(&threat_circle.get_center_point_2dd())
+        });
       tank.rotate_turret(&target_point);
     }
+    self.detect_deadlock(time_delta);
+    self.detect_stuck(time_delta);
+    if self.back_up_time_remaining > 0. {
+      // Back away from the blocker before replanning
+      self.back_up_time_remaining -= time_delta;
+      tank.borrow_mut().go(&self.destination);
+      return;
+    }
+    if self.ai_profile.evasion_enabled {
+      // Sidestep out of the line of fire of an incoming bullet
+      let sidestep_option: Option<Point2DD> =
+        self.evader.find_sidestep(&*tank.borrow());
+      if let Some(sidestep) = sidestep_option {
+        self.tank_cartographer.release_path();
+        tank.borrow_mut().go(&sidestep);
+        return;
+      }
+    }
     {
       // Move toward nearest ammo dump
       let ammo: usize = tank.borrow().get_ammo();
-      if ammo < 1 {
+      if ammo <= self.ai_profile.resupply_threshold {
         let closest_ammo_dump_circle_option: Option<Circle> =
           tank.borrow().get_closest_ammo_dump_circle();
         if let Some(closest_ammo_dump_circle) = closest_ammo_dump_circle_option
@@ -168,21 +479,65 @@ impl TankOperator for DefaultTankOperator {
             tank.borrow().get_body_heading(),
           );
           tank.borrow_mut().go(&destination);
+          return;
+        }
+        if ammo < 1 {
+          return;
         }
-        return;
       }
     }
-    // Move toward nearest enemy tank
+    // Badly damaged, crippled, or reloading tanks retreat to cover
+    let mut cover_destination: Option<Point2DD> = None;
+    if let Some(threat_circle) = self.threat_circle {
+      let retreating: bool = {
+        let tank = tank.borrow();
+        tank.get_damage() >= TANK_RETREAT_DAMAGE_FRACTION * TANK_DAMAGE_MAX
+          || tank.is_part_disabled(TankPart::TreadLeft)
+          || tank.is_part_disabled(TankPart::TreadRight)
+          || tank.is_part_disabled(TankPart::Turret)
+          || tank.is_reloading()
+      };
+      if retreating {
+        let heading: f64 = tank.borrow().get_body_heading();
+        cover_destination = self.seek_cover(threat_circle, heading);
+      }
+    }
+    if cover_destination.is_none() {
+      self.cover_circle = None;
+    }
+    // Move toward the selected enemy tank where it was last seen
     let mut thread_rng: ThreadRng = rand::thread_rng();
     let uniform = Uniform::from(0.0..1.);
-    if let Some(enemy_circle) = self.target_circle {
+    let exploration_circle_option: Option<Circle> =
+      if cover_destination.is_none() && self.threat_circle.is_none() {
+        self.find_exploration_circle()
+      } else {
+        None
+      };
+    if let Some(destination) = cover_destination {
+      tank.borrow_mut().go(&destination);
+      // Leave mines behind for a pursuer while any remain
+      let random_number = uniform.sample(&mut thread_rng);
+      if tank.borrow().get_mine_count() > 0
+        && random_number < time_delta * TANK_MINE_LAYING_PROBABILITY
+      {
+        tank.borrow_mut().lay_mine();
+      }
+    } else if let Some(enemy_circle) = self.threat_circle {
       let destination: Point2DD =
         self.get_first_step(enemy_circle, tank.borrow().get_body_heading());
       tank.borrow_mut().go(&destination);
+    } else if let Some(exploration_circle) = exploration_circle_option {
+      // Scout the area the team has not seen for the longest time
+      let destination: Point2DD = self
+        .get_first_step(exploration_circle, tank.borrow().get_body_heading());
+      tank.borrow_mut().go(&destination);
     } else {
       // Move randomly
       let random_number = uniform.sample(&mut thread_rng);
-      if random_number < time_delta * TANK_DRIFT_PROBABILITY {
+      if random_number
+        < time_delta * self.ai_profile.aggression * TANK_DRIFT_PROBABILITY
+      {
         let uniform_drift = Uniform::from(-1.0..=1.0);
         let drift_x = uniform_drift.sample(&mut thread_rng);
         let drift_y = uniform_drift.sample(&mut thread_rng);
@@ -195,11 +550,30 @@ impl TankOperator for DefaultTankOperator {
       // TODO: Is this the best way to do this?
       self.start_state_space_node.set_point_xy(&self.center);
       self.a_star.reset(self.start_state_space_node);
+      self.tank_cartographer.release_path();
     }
     // Fire randomly
     let random_number = uniform.sample(&mut thread_rng);
-    if random_number < time_delta * TANK_FIRING_PROBABILITY {
-      tank.borrow_mut().fire();
+    if random_number
+      < time_delta * self.ai_profile.aggression * TANK_FIRING_PROBABILITY
+    {
+      let lobbing: bool = self
+        .weapon_selector
+        .should_lob_shell(&*tank.borrow(), self.threat_circle.as_ref());
+      if lobbing {
+        // Only lobbing when there is a threat circle
+        let threat_center: Point2DD =
+          self.threat_circle.unwrap().get_center_point_2dd();
+        tank.borrow_mut().lob_shell(&threat_center);
+      } else {
+        let bullet_kind: BulletKind = self
+          .weapon_selector
+          .select_weapon(&*tank.borrow(), self.threat_circle.as_ref());
+        let mut tank = tank.borrow_mut();
+        tank.select_weapon(bullet_kind);
+        tank.fire();
+      }
+      self.sample_aim_offset();
     }
   }
 }
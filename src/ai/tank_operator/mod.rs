@@ -2,10 +2,10 @@
 //! - Tank Operater for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-04-04
-//! - Updated: 2023-06-25
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -31,6 +31,9 @@ pub trait TankOperator {
 
   fn get_tank(&self) -> Rc<RefCell<dyn Tank>>;
 
+  // The ID of the tank operator of the enemy tank currently targeted
+  fn get_target_id(&self) -> Option<usize>;
+
   fn update(
     &mut self,
     time_delta: f64,
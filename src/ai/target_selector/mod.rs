@@ -0,0 +1,214 @@
+// =============================================================================
+//! - Target Selector for CroftSoft Mars
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::ai::perception::KnownEnemy;
+use crate::constant::{
+  BULLET_RADIUS, SHELL_RANGE_MAX, SHELL_RANGE_MIN, TARGET_SELECTOR_ALLY_WEIGHT,
+  TARGET_SELECTOR_DAMAGE_WEIGHT, TARGET_SELECTOR_LINE_OF_FIRE_WEIGHT,
+  TARGET_SELECTOR_STALENESS_WEIGHT, TARGET_SELECTOR_VULNERABLE_WEIGHT,
+};
+use crate::model::tank::archetype::TankArchetype;
+use crate::model::tank::{Color, TankAccessor};
+use crate::world::World;
+use com_croftsoft_core::math::geom::circle::{Circle, CircleAccessor};
+use com_croftsoft_core::math::geom::point_2dd::Point2DD;
+use std::rc::Weak;
+
+// Scores a candidate where the candidate with the highest score is selected
+pub type TargetScorer = Box<dyn Fn(&TargetCandidate) -> f64>;
+
+pub struct Target {
+  pub circle: Circle,
+  // The ID of the tank operator of the target tank
+  pub id: usize,
+}
+
+pub struct TargetCandidate {
+  // How many friendly tanks other than this one already have this target
+  pub ally_count: usize,
+  // The archetype of the tank selecting the target
+  pub archetype: TankArchetype,
+  pub damage: f64,
+  pub distance: f64,
+  // True if no obstacle or other tank is in the way
+  pub line_of_fire: bool,
+  pub reloading: bool,
+  pub sparking: bool,
+  // Zero if the target is currently visible to the team
+  pub time_since_seen: f64,
+}
+
+pub struct TargetSelector {
+  target_scorer: TargetScorer,
+  world: Weak<dyn World>,
+}
+
+impl TargetSelector {
+  // Prefers targets that are close, damaged, vulnerable, in the line of fire,
+  // recently seen, and not already engaged by allies
+  pub fn default_score(candidate: &TargetCandidate) -> f64 {
+    let mut score: f64 = -candidate.distance;
+    score -= TARGET_SELECTOR_STALENESS_WEIGHT * candidate.time_since_seen;
+    score += TARGET_SELECTOR_DAMAGE_WEIGHT * candidate.damage;
+    if candidate.reloading || candidate.sparking {
+      score += TARGET_SELECTOR_VULNERABLE_WEIGHT;
+    }
+    // Artillery can lob shells over whatever is in the way
+    let lobbable: bool = candidate.archetype.indirect_fire
+      && (SHELL_RANGE_MIN..=SHELL_RANGE_MAX).contains(&candidate.distance);
+    if candidate.line_of_fire || lobbable {
+      score += TARGET_SELECTOR_LINE_OF_FIRE_WEIGHT;
+    }
+    score - TARGET_SELECTOR_ALLY_WEIGHT * candidate.ally_count as f64
+  }
+
+  // The enemy tank of the tank operator if the team still knows about it
+  pub fn find_target(
+    &self,
+    color: Color,
+    tank_operator_id: usize,
+  ) -> Option<Target> {
+    let world = self.world.upgrade().unwrap();
+    let known_enemy: KnownEnemy = world
+      .get_perception()
+      .borrow()
+      .get_known_enemy(color, tank_operator_id)?;
+    Some(Target {
+      circle: known_enemy.circle,
+      id: tank_operator_id,
+    })
+  }
+
+  // True if no obstacle or other tank intersects the line of fire
+  fn is_line_of_fire_clear(
+    &self,
+    from_circle: &Circle,
+    to_circle: &Circle,
+  ) -> bool {
+    let from: Point2DD = from_circle.get_center_point_2dd();
+    let to: Point2DD = to_circle.get_center_point_2dd();
+    let world = self.world.upgrade().unwrap();
+    for obstacle in world.get_obstacles().borrow().iter() {
+      if TargetSelector::is_on_segment(&obstacle.get_circle(), &from, &to) {
+        return false;
+      }
+    }
+    for tank_operator in world.get_tank_operators().borrow().iter() {
+      let tank = tank_operator.get_tank();
+      let tank = tank.borrow();
+      if !tank.is_active() {
+        continue;
+      }
+      let circle: Circle = tank.get_circle();
+      let center: Point2DD = circle.get_center_point_2dd();
+      if center.distance_to(&from) <= from_circle.radius
+        || center.distance_to(&to) <= to_circle.radius
+      {
+        continue;
+      }
+      if TargetSelector::is_on_segment(&circle, &from, &to) {
+        return false;
+      }
+    }
+    true
+  }
+
+  // True if a bullet travelling along the segment would hit the circle
+  pub fn is_on_segment(
+    circle: &Circle,
+    from: &Point2DD,
+    to: &Point2DD,
+  ) -> bool {
+    let length: f64 = from.distance_to(to);
+    if length <= 0. {
+      return false;
+    }
+    let unit_x: f64 = (to.x - from.x) / length;
+    let unit_y: f64 = (to.y - from.y) / length;
+    let delta_x: f64 = circle.center_x - from.x;
+    let delta_y: f64 = circle.center_y - from.y;
+    let along: f64 = (delta_x * unit_x + delta_y * unit_y).clamp(0., length);
+    let closest =
+      Point2DD::new(from.x + along * unit_x, from.y + along * unit_y);
+    closest.distance_to(&circle.get_center_point_2dd())
+      < circle.radius + BULLET_RADIUS
+  }
+
+  // Prefers the closest target regardless of anything else
+  pub fn nearest_score(candidate: &TargetCandidate) -> f64 {
+    -candidate.distance
+  }
+
+  pub fn new(world: Weak<dyn World>) -> Self {
+    Self {
+      target_scorer: Box::new(TargetSelector::default_score),
+      world,
+    }
+  }
+
+  // The enemy tank known to the team with the highest score
+  pub fn select_target(
+    &self,
+    tank: &dyn TankAccessor,
+    tank_operator_id: usize,
+  ) -> Option<Target> {
+    let color = tank.get_color();
+    let tank_circle: Circle = tank.get_circle();
+    let tank_center: Point2DD = tank_circle.get_center_point_2dd();
+    let world = self.world.upgrade().unwrap();
+    let known_enemies: Vec<KnownEnemy> =
+      world.get_perception().borrow().get_known_enemies(color);
+    let tank_operators = world.get_tank_operators();
+    let tank_operators = tank_operators.borrow();
+    let mut best_score: f64 = f64::NEG_INFINITY;
+    let mut best_target: Option<Target> = None;
+    for known_enemy in known_enemies {
+      let id: usize = known_enemy.id;
+      let circle: Circle = known_enemy.circle;
+      let ally_count: usize = tank_operators
+        .iter()
+        .filter(|ally_tank_operator| {
+          ally_tank_operator.get_id() != tank_operator_id
+            && ally_tank_operator.get_target_id() == Some(id)
+            && ally_tank_operator.get_tank().borrow().get_color() == color
+        })
+        .count();
+      let candidate = TargetCandidate {
+        ally_count,
+        archetype: tank.get_archetype(),
+        damage: known_enemy.damage,
+        distance: tank_center.distance_to(&circle.get_center_point_2dd()),
+        line_of_fire: self.is_line_of_fire_clear(&tank_circle, &circle),
+        reloading: known_enemy.reloading,
+        sparking: known_enemy.sparking,
+        time_since_seen: known_enemy.time_since_seen,
+      };
+      let score: f64 = (self.target_scorer)(&candidate);
+      if score > best_score {
+        best_score = score;
+        best_target = Some(Target {
+          circle,
+          id,
+        });
+      }
+    }
+    best_target
+  }
+
+  pub fn set_target_scorer(
+    &mut self,
+    target_scorer: TargetScorer,
+  ) {
+    self.target_scorer = target_scorer;
+  }
+}
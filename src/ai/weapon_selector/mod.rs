@@ -0,0 +1,144 @@
+// =============================================================================
+//! - Weapon Selector for CroftSoft Mars
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::ai::target_selector::TargetSelector;
+use crate::constant::{
+  SHELL_RANGE_MAX, SHELL_RANGE_MIN, WEAPON_HIGH_EXPLOSIVE_SPLASH_RADIUS,
+};
+use crate::model::bullet::BulletKind;
+use crate::model::tank::TankAccessor;
+use crate::world::World;
+use com_croftsoft_core::math::geom::circle::{Circle, CircleAccessor};
+use com_croftsoft_core::math::geom::point_2dd::Point2DD;
+use std::rc::Weak;
+
+// Chooses the weapon to fire for the situation
+pub struct WeaponSelector {
+  world: Weak<dyn World>,
+}
+
+impl WeaponSelector {
+  // True if another known enemy tank or an ammo dump is close enough to the
+  // target to be caught in the explosion of a high-explosive bullet
+  fn is_splash_worthwhile(
+    &self,
+    tank: &dyn TankAccessor,
+    target_circle: &Circle,
+  ) -> bool {
+    let target_center: Point2DD = target_circle.get_center_point_2dd();
+    let reach: f64 = target_circle.radius + WEAPON_HIGH_EXPLOSIVE_SPLASH_RADIUS;
+    let world = self.world.upgrade().unwrap();
+    let near_enemy: bool = world
+      .get_perception()
+      .borrow()
+      .get_known_enemies(tank.get_color())
+      .iter()
+      .any(|known_enemy| {
+        let enemy_center: Point2DD = known_enemy.circle.get_center_point_2dd();
+        enemy_center != target_center
+          && enemy_center.distance_to(&target_center)
+            <= reach + known_enemy.circle.radius
+      });
+    near_enemy
+      || world.get_ammo_dumps().borrow().iter().any(|ammo_dump| {
+        let ammo_dump_circle: Circle = ammo_dump.get_circle();
+        ammo_dump_circle
+          .get_center_point_2dd()
+          .distance_to(&target_center)
+          <= reach + ammo_dump_circle.radius
+      })
+  }
+
+  // True if an obstacle lies in the line of fire to the target
+  fn is_shielded_by_obstacle(
+    &self,
+    tank: &dyn TankAccessor,
+    target_circle: &Circle,
+  ) -> bool {
+    let from: Point2DD = tank.get_center();
+    let to: Point2DD = target_circle.get_center_point_2dd();
+    self
+      .world
+      .upgrade()
+      .unwrap()
+      .get_obstacles()
+      .borrow()
+      .iter()
+      .any(|obstacle| {
+        TargetSelector::is_on_segment(&obstacle.get_circle(), &from, &to)
+      })
+  }
+
+  pub fn new(world: Weak<dyn World>) -> Self {
+    Self {
+      world,
+    }
+  }
+
+  // Armor-piercing when an obstacle shields the target, high-explosive when
+  // the explosion would also catch something else, and standard otherwise.
+  // Falls back to any weapon that still has ammo.
+  pub fn select_weapon(
+    &self,
+    tank: &dyn TankAccessor,
+    target_circle: Option<&Circle>,
+  ) -> BulletKind {
+    let preferred: BulletKind = match target_circle {
+      Some(target_circle)
+        if self.is_shielded_by_obstacle(tank, target_circle) =>
+      {
+        BulletKind::ArmorPiercing
+      },
+      Some(target_circle) if self.is_splash_worthwhile(tank, target_circle) => {
+        BulletKind::HighExplosive
+      },
+      _ => BulletKind::Standard,
+    };
+    [
+      preferred,
+      BulletKind::Standard,
+      BulletKind::HighExplosive,
+      BulletKind::ArmorPiercing,
+    ]
+    .into_iter()
+    .find(|bullet_kind| {
+      tank
+        .get_weapon(*bullet_kind)
+        .map_or(false, |weapon| weapon.ammo > 0)
+    })
+    .unwrap_or(preferred)
+  }
+
+  // True if the tank can lob a shell at the target and a bullet would either
+  // be stopped by an obstacle or fall short
+  pub fn should_lob_shell(
+    &self,
+    tank: &dyn TankAccessor,
+    target_circle: Option<&Circle>,
+  ) -> bool {
+    let Some(target_circle) = target_circle else {
+      return false;
+    };
+    if !tank.get_archetype().indirect_fire {
+      return false;
+    }
+    let distance: f64 = tank
+      .get_center()
+      .distance_to(&target_circle.get_center_point_2dd());
+    if !(SHELL_RANGE_MIN..=SHELL_RANGE_MAX).contains(&distance) {
+      return false;
+    }
+    distance > tank.get_archetype().bullet.range
+      || self.is_shielded_by_obstacle(tank, target_circle)
+  }
+}
@@ -2,10 +2,10 @@
 //! - Component trait for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-06-03
-//! - Updated: 2023-09-04
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -14,6 +14,7 @@
 use com_croftsoft_lib_role::{InitializerMut, UpdaterMut};
 
 pub mod canvas;
+pub mod fog;
 pub mod node;
 pub mod path;
 pub mod pause;
@@ -2,15 +2,16 @@
 //! - Root Component for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-03-11
-//! - Updated: 2023-09-04
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
+use super::fog::FogComponent;
 use super::node::NodeComponent;
 use super::path::PathComponent;
 use super::pause::PauseComponent;
@@ -29,8 +30,9 @@ use web_sys::{Document, HtmlCollection};
 
 pub struct RootComponent {
   canvas_component: Rc<RefCell<CanvasComponent>>,
-  components: [Rc<RefCell<dyn Component>>; 6],
+  components: [Rc<RefCell<dyn Component>>; 7],
   // events: Rc<RefCell<Events>>,
+  fog_component: Rc<RefCell<FogComponent>>,
   node_component: Rc<RefCell<NodeComponent>>,
   path_component: Rc<RefCell<PathComponent>>,
   pause_component: Rc<RefCell<PauseComponent>>,
@@ -51,6 +53,8 @@ impl RootComponent {
       inputs.clone(),
       root,
     )));
+    let fog_component =
+      Rc::new(RefCell::new(FogComponent::new("fog", inputs.clone())));
     let node_component =
       Rc::new(RefCell::new(NodeComponent::new("node", inputs.clone())));
     let path_component =
@@ -68,8 +72,9 @@ impl RootComponent {
     //   Rc::new(RefCell::new(ResetComponent::new("reset", inputs.clone())));
     // let speed_component =
     //   Rc::new(RefCell::new(SpeedComponent::new("speed", inputs.clone())));
-    let components: [Rc<RefCell<dyn Component>>; 6] = [
+    let components: [Rc<RefCell<dyn Component>>; 7] = [
       canvas_component.clone(),
+      fog_component.clone(),
       node_component.clone(),
       path_component.clone(),
       pause_component.clone(),
@@ -83,6 +88,7 @@ impl RootComponent {
       canvas_component,
       components,
       // events,
+      fog_component,
       node_component,
       path_component,
       pause_component,
@@ -95,6 +101,7 @@ impl RootComponent {
 impl Component for RootComponent {
   fn make_html(&self) -> String {
     let canvas_html: String = self.canvas_component.borrow().make_html();
+    let fog_html: String = self.fog_component.borrow().make_html();
     let node_html: String = self.node_component.borrow().make_html();
     let path_html: String = self.path_component.borrow().make_html();
     let pause_html: String = self.pause_component.borrow().make_html();
@@ -111,6 +118,7 @@ impl Component for RootComponent {
       // speed_html,
       node_html,
       path_html,
+      fog_html,
       update_rate_html,
       // time_html,
       pause_html,
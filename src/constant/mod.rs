@@ -2,28 +2,37 @@
 //! - Constant values for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023-2024 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-03-11
-//! - Updated: 2024-02-23
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
 use crate::configuration::Configuration;
+use crate::model::tank::Color;
 use com_croftsoft_core::math::geom::rectangle::Rectangle;
 use core::f64::consts::TAU;
 
+pub const A_STAR_AMMO_DUMP_HAZARD_WEIGHT: f64 = 1.;
 pub const A_STAR_DIRECTIONS: usize = 8;
+pub const A_STAR_EXPOSURE_WEIGHT: f64 = 0.5;
 pub const A_STAR_LOOPS: usize = 100;
+pub const A_STAR_OBSTACLE_PREDICTION_TIME_MAX: f64 = 3.;
+pub const A_STAR_RESERVATION_TIME_WINDOW: f64 = 1.;
 pub const A_STAR_STEP_SIZE: f64 = TANK_RADIUS / 2.;
 pub const AMMO_DUMP_AMMO_GROWTH_RATE: f64 = 0.5;
 pub const AMMO_DUMP_AMMO_MAX: f64 = 30.;
+// Seconds a team must hold an ammo dump uncontested to take ownership
+pub const AMMO_DUMP_CAPTURE_TIME_SECONDS: f64 = 3.;
 pub const AMMO_DUMP_COOLING_TIME_SECONDS: f64 = 3.;
 pub const AMMO_DUMP_COUNT_MAXIMUM: usize = 10;
 pub const AMMO_DUMP_EXPLOSION_FACTOR: f64 = 3.;
 pub const AMMO_DUMP_FILL_STYLE: &str = "#b5a642";
+// Multiplies the ammo growth rate of an ammo dump owned by a team
+pub const AMMO_DUMP_OWNED_GROWTH_FACTOR: f64 = 2.;
 pub const AMMO_DUMP_RANDOM_PLACEMENT_ATTEMPTS_MAX: usize = 100;
 pub const AMMO_DUMP_STROKE_STYLE: &str = "black";
 pub const AMMO_DUMP_Z: f64 = 0.1;
@@ -38,6 +47,11 @@ pub const BULLET_DAMAGE: f64 = 1.;
 pub const BULLET_FILL_STYLE: &str = "black";
 pub const BULLET_RADIUS: f64 = 3.;
 pub const BULLET_RANGE: f64 = 200.;
+pub const BULLET_RICOCHET_DAMAGE_FACTOR: f64 = 0.5;
+// Minimum angle in radians between the path of a bullet and the surface normal
+// of an obstacle for the bullet to glance off instead of striking it
+pub const BULLET_RICOCHET_INCIDENCE_ANGLE_MIN: f64 = TAU / 6.;
+pub const BULLET_RICOCHET_RANGE_FACTOR: f64 = 0.5;
 pub const BULLET_STROKE_STYLE: &str = "red";
 pub const BULLET_VELOCITY: f64 = 90.;
 pub const BULLET_Z: f64 = 2.;
@@ -45,20 +59,45 @@ pub const CONFIGURATION: Configuration = Configuration {
   bounds: BOUNDS,
   update_period_millis_initial: UPDATE_PERIOD_MILLIS,
 };
+pub const COVER_CANDIDATE_COUNT_MAXIMUM: usize = 3;
+pub const EXPLORATION_CELL_SIZE: f64 = 50.;
+pub const EXPLORATION_DISTANCE_WEIGHT: f64 = 0.05;
+// Seconds since last seen assumed for cells that have never been seen
+pub const EXPLORATION_STALENESS_INITIAL: f64 = 60.;
+// Fraction of the explosion damage dealt at the edge of the explosion
+pub const EXPLOSION_DAMAGE_EDGE_FRACTION: f64 = 0.25;
+// Outward speed given to a pushed model per unit of damage received
+pub const EXPLOSION_KNOCKBACK_SPEED_PER_DAMAGE: f64 = 40.;
 pub const EXPLOSION_RADIUS_DECAY_RATE: f64 = 10.;
 pub const EXPLOSION_RADIUS_MINIMUM: f64 = 1.;
 pub const EXPLOSION_FILL_STYLE: &str = "yellow";
 pub const EXPLOSION_STROKE_STYLE: &str = "red";
+pub const FOG_CELL_SIZE: f64 = 10.;
+pub const FOG_FILL_STYLE: &str = "rgba(0, 0, 0, 0.3)";
+// The team of the player whose view the fog shows
+pub const FOG_VIEWER_COLOR: Color = Color::BLUE;
 pub static FONT: &str = "bold 17px monospace";
 pub static INFO: &str = "CroftSoft Mars \
   v0.0.4-SNAPSHOT \
   Copyright 2023-2024 \
   CroftSoft Inc";
 pub const MILLIS_PER_SECOND: f64 = 1_000.;
+pub const MINE_ARMING_TIME_SECONDS: f64 = 2.;
+pub const MINE_EXPLOSION_DAMAGE: f64 = 2.;
+pub const MINE_EXPLOSION_RADIUS: f64 = 30.;
+pub const MINE_RADIUS: f64 = 6.;
+pub const MINE_STROKE_STYLE: &str = "black";
+pub const MINE_Z: f64 = 0.15;
 pub const NODE_STROKE_STYLE: &str = "black";
 pub const OBSTACLE_BULLET_DAMAGE_MULTIPLIER: f64 = 10.;
 pub const OBSTACLE_COUNT_MAXIMUM: usize = 10;
 pub const OBSTACLE_FILL_STYLE: &str = "#40826D";
+// Minimum damage from a single hit that splits an obstacle into fragments
+pub const OBSTACLE_FRAGMENTATION_DAMAGE_MIN: f64 = 15.;
+// Minimum radius after the hit for an obstacle to split into fragments
+pub const OBSTACLE_FRAGMENTATION_RADIUS_MIN: f64 = 30.;
+pub const OBSTACLE_FRAGMENT_COUNT_MAX: usize = 3;
+pub const OBSTACLE_FRAGMENT_SPEED: f64 = 40.;
 pub const OBSTACLE_JERK_MAGNITUDE_MAX: f64 = 10.;
 pub const OBSTACLE_RADIUS_MAX: f64 = 60.;
 pub const OBSTACLE_RADIUS_MIN: f64 = 10.;
@@ -68,25 +107,112 @@ pub const OBSTACLE_STROKE_STYLE: &str = "black";
 pub const OBSTACLE_Z: f64 = 0.2;
 pub const OVERLAY_FILL_STYLE: &str = "black";
 pub const OVERLAY_REFRESH_PERIOD_MILLIS: f64 = 1_000.;
+pub const PERCEPTION_MEMORY_TIME_SECONDS: f64 = 10.;
+pub const PERCEPTION_SEARCH_RADIUS: f64 = TANK_RADIUS * 2.;
+pub const PERCEPTION_SIGHT_RADIUS: f64 = 250.;
+// Height of the arc of a shell as a fraction of the distance travelled
+pub const SHELL_APEX_FRACTION: f64 = 0.25;
+pub const SHELL_DAMAGE: f64 = 2.;
+pub const SHELL_EXPLOSION_RADIUS: f64 = 35.;
+pub const SHELL_FILL_STYLE: &str = "black";
+pub const SHELL_RADIUS: f64 = 4.;
+pub const SHELL_RANGE_MAX: f64 = 450.;
+pub const SHELL_RANGE_MIN: f64 = 100.;
+pub const SHELL_SHADOW_FILL_STYLE: &str = "rgba(0, 0, 0, 0.3)";
+pub const SHELL_STROKE_STYLE: &str = "red";
+pub const SHELL_VELOCITY: f64 = 80.;
+pub const SHELL_WARNING_STROKE_STYLE: &str = "orange";
+pub const SHELL_Z: f64 = 3.;
+pub const TANK_ACCELERATION_METERS_PER_SECOND_SQUARED: f64 = 30.;
 pub const TANK_AMMO_INITIAL: usize = TANK_DAMAGE_MAX as usize + 1;
 pub const TANK_AMMO_MAX: usize = 30;
+pub const TANK_ARMOR_FRONT_MULTIPLIER: f64 = 0.5;
+pub const TANK_ARMOR_REAR_MULTIPLIER: f64 = 1.5;
+pub const TANK_ARMOR_SIDE_MULTIPLIER: f64 = 1.;
 pub const TANK_BODY_ROTATION_SPEED_RADIANS_PER_SECOND: f64 = TAU / 6.;
 pub const TANK_BURNING_DURATION_SECONDS: f64 = 5.;
 pub const TANK_COUNT_MAXIMUM: usize = 5;
 pub const TANK_DAMAGE_MAX: f64 = 2.;
+pub const TANK_DEADLOCK_TIME_SECONDS: f64 = 2.;
+pub const TANK_DECELERATION_METERS_PER_SECOND_SQUARED: f64 = 60.;
 pub const TANK_DRIFT_PROBABILITY: f64 = 0.1;
 pub const TANK_FILL_STYLE_BLUE: &str = "rgb(99, 127, 255)";
 pub const TANK_FILL_STYLE_RED: &str = "rgb(255, 127, 99)";
 pub const TANK_FILL_STYLE_SPARKING: &str = "red";
 pub const TANK_FIRING_PROBABILITY: f64 = 1.;
+pub const TANK_KNOCKBACK_DECELERATION_METERS_PER_SECOND_SQUARED: f64 = 150.;
+pub const TANK_MINE_COUNT_INITIAL: usize = 2;
+pub const TANK_MINE_LAYING_PROBABILITY: f64 = 0.2;
 pub const TANK_RADIUS: f64 = 25.;
+pub const TANK_RECOVERY_EXCLUSION_TIME_SECONDS: f64 = 5.;
+pub const TANK_RECOVERY_TIME_SECONDS: f64 = 2.;
 pub const TANK_RELOAD_TIME_SECONDS: f64 = 2.;
+// Tanks retreat to cover once damaged beyond this fraction of the maximum
+pub const TANK_RETREAT_DAMAGE_FRACTION: f64 = 0.5;
+// Tanks only back up to destinations this close
+pub const TANK_REVERSE_DISTANCE_MAX: f64 = A_STAR_STEP_SIZE * 2.;
+pub const TANK_REVERSE_SPEED_FRACTION: f64 = 0.5;
 pub const TANK_SPARKING_DURATION_SECONDS: f64 = 0.1;
 pub const TANK_SPEED_METERS_PER_SECOND: f64 = 30.;
+pub const TANK_STALLED_DISTANCE_MAX: f64 = 0.01;
 pub const TANK_STROKE_STYLE: &str = "black";
+pub const TANK_STUCK_DISTANCE_MIN: f64 = TANK_RADIUS / 5.;
+pub const TANK_STUCK_WINDOW_SECONDS: f64 = 4.;
 pub const TANK_TREAD_LENGTH: f64 = 5.;
+pub const TANK_TREAD_REPAIR_TIME_SECONDS: f64 = 3.;
+// Fraction of the tank radius covered by the turret
+pub const TANK_TURRET_RADIUS_FRACTION: f64 = 0.4;
+pub const TANK_TURRET_REPAIR_TIME_SECONDS: f64 = 3.;
 pub const TANK_TURRET_ROTATION_SPEED_RADIANS_PER_SECOND: f64 = TAU / 2.;
+// Turret rotation to either side of the body heading when sweeping
+pub const TANK_TURRET_SWEEP_ANGLE: f64 = TAU / 6.;
+// The first level with veteran and sniper tanks in place of rookies
+pub const TANK_VETERAN_LEVEL_MIN: usize = 3;
+pub const TANK_YIELD_TIME_SECONDS: f64 = 2.;
 pub const TANK_Z: f64 = 1.;
+pub const TARGET_SELECTOR_ALLY_WEIGHT: f64 = 50.;
+pub const TARGET_SELECTOR_DAMAGE_WEIGHT: f64 = 50.;
+pub const TARGET_SELECTOR_LINE_OF_FIRE_WEIGHT: f64 = 150.;
+pub const TARGET_SELECTOR_STALENESS_WEIGHT: f64 = 10.;
+pub const TARGET_SELECTOR_VULNERABLE_WEIGHT: f64 = 50.;
+pub const TERRAIN_CELL_SIZE: f64 = 20.;
+// Meters of bullet range used up per meter flown low over mud
+pub const TERRAIN_MUD_BULLET_DRAG: f64 = 1.5;
+pub const TERRAIN_MUD_FILL_STYLE: &str = "rgb(150, 95, 70)";
+// Noise values below this are mud
+pub const TERRAIN_MUD_NOISE_MAX: f64 = 0.25;
+pub const TERRAIN_MUD_SPEED_MULTIPLIER: f64 = 0.5;
+// Number of terrain cells between the samples of the value noise
+pub const TERRAIN_NOISE_CELL_SIZE: usize = 6;
+pub const TERRAIN_ROAD_COUNT: usize = 2;
+pub const TERRAIN_ROAD_FILL_STYLE: &str = "rgb(205, 185, 160)";
+pub const TERRAIN_ROAD_SPEED_MULTIPLIER: f64 = 1.25;
+pub const TERRAIN_ROCK_FILL_STYLE: &str = "rgb(165, 130, 115)";
+// Noise values at or above this are rock
+pub const TERRAIN_ROCK_NOISE_MIN: f64 = 0.8;
+pub const TERRAIN_ROCK_SPEED_MULTIPLIER: f64 = 0.7;
+// Meters of bullet range used up per meter flown low over sand
+pub const TERRAIN_SAND_BULLET_DRAG: f64 = 1.25;
+pub const TERRAIN_SAND_FILL_STYLE: &str = "rgb(250, 180, 120)";
+// Noise values at or above this and below rock are sand
+pub const TERRAIN_SAND_NOISE_MIN: f64 = 0.6;
+pub const TERRAIN_SAND_SPEED_MULTIPLIER: f64 = 0.8;
 pub const TIME_DELTA: f64 = 1. / UPDATES_PER_SECOND;
 pub const UPDATE_PERIOD_MILLIS: f64 = MILLIS_PER_SECOND / UPDATES_PER_SECOND;
 pub const UPDATES_PER_SECOND: f64 = 60.;
+pub const WEAPON_ARMOR_PIERCING_AMMO_FRACTION: f64 = 0.2;
+pub const WEAPON_ARMOR_PIERCING_DAMAGE_FACTOR: f64 = 1.5;
+pub const WEAPON_ARMOR_PIERCING_RELOAD_FACTOR: f64 = 2.;
+pub const WEAPON_HIGH_EXPLOSIVE_AMMO_FRACTION: f64 = 0.3;
+pub const WEAPON_HIGH_EXPLOSIVE_RELOAD_FACTOR: f64 = 1.5;
+// Fraction of the bullet damage dealt by the explosion on impact
+pub const WEAPON_HIGH_EXPLOSIVE_SPLASH_DAMAGE_FRACTION: f64 = 0.5;
+pub const WEAPON_HIGH_EXPLOSIVE_SPLASH_RADIUS: f64 = 20.;
+pub const WEAPON_HIGH_EXPLOSIVE_VELOCITY_FACTOR: f64 = 0.75;
+// Initial ammo for the weapons other than the standard shell
+pub const WEAPON_SPECIAL_AMMO_INITIAL: usize = 1;
+pub const WRECK_BURN_OUT_TIME_SECONDS: f64 = 30.;
+// Zero disables the explosion when a wreck is destroyed
+pub const WRECK_EXPLOSION_DAMAGE: f64 = 1.;
+pub const WRECK_EXPLOSION_RADIUS: f64 = 40.;
+pub const WRECK_FILL_STYLE: &str = "#4A4A4A";
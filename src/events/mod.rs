@@ -2,10 +2,10 @@
 //! - Events for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-03-11
-//! - Updated: 2023-06-03
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -15,6 +15,7 @@
 
 #[derive(Default)]
 pub struct Events {
+  pub tank_stuck_count: usize,
   pub time_to_update: bool,
   pub updated: bool,
   pub update_period_millis_changed: Option<f64>,
@@ -22,6 +23,7 @@ pub struct Events {
 
 impl Events {
   pub fn clear(&mut self) {
+    self.tank_stuck_count = 0;
     self.time_to_update = false;
     self.update_period_millis_changed = None;
     self.updated = false;
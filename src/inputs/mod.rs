@@ -2,10 +2,10 @@
 //! - Inputs for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-03-11
-//! - Updated: 2023-06-24
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -16,6 +16,7 @@
 #[derive(Default)]
 pub struct Inputs {
   pub current_time_millis: f64,
+  pub fog_display_change_requested: Option<bool>,
   pub node_display_change_requested: Option<bool>,
   pub path_display_change_requested: Option<bool>,
   pub pause_change_requested: Option<bool>,
@@ -27,6 +28,7 @@ pub struct Inputs {
 impl Inputs {
   pub fn clear(&mut self) {
     self.current_time_millis = 0.;
+    self.fog_display_change_requested = None;
     self.node_display_change_requested = None;
     self.path_display_change_requested = None;
     self.pause_change_requested = None;
@@ -2,10 +2,10 @@
 //! - Default Ammo Dump for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-04-27
-//! - Updated: 2023-09-04
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -14,9 +14,11 @@
 use self::state::State;
 use super::{AmmoDump, AmmoDumpAccessor};
 use crate::constant::{
-  AMMO_DUMP_AMMO_GROWTH_RATE, AMMO_DUMP_AMMO_MAX, AMMO_DUMP_EXPLOSION_FACTOR,
-  AMMO_DUMP_Z,
+  AMMO_DUMP_AMMO_GROWTH_RATE, AMMO_DUMP_AMMO_MAX,
+  AMMO_DUMP_CAPTURE_TIME_SECONDS, AMMO_DUMP_EXPLOSION_FACTOR,
+  AMMO_DUMP_OWNED_GROWTH_FACTOR, AMMO_DUMP_Z,
 };
+use crate::model::tank::Color;
 use crate::model::{Damageable, Model, ModelAccessor};
 use crate::world::factory::WorldFactory;
 use crate::world::World;
@@ -30,9 +32,14 @@ pub struct DefaultAmmoDump {
   ammo: f64,
   ammo_growth_rate: f64,
   ammo_max: f64,
+  // Seconds the capturing team has held the ammo dump uncontested
+  capture_time: f64,
+  capturer: Option<Color>,
   circle: Circle,
+  contested: bool,
   factory: Weak<dyn WorldFactory>,
   id: usize,
+  owner: Option<Color>,
   state: State,
   updated: bool,
   world: Weak<dyn World>,
@@ -57,9 +64,13 @@ impl DefaultAmmoDump {
       ammo: 0.,
       ammo_growth_rate: AMMO_DUMP_AMMO_GROWTH_RATE,
       ammo_max: AMMO_DUMP_AMMO_MAX,
+      capture_time: 0.,
+      capturer: None,
       circle,
+      contested: false,
       factory,
       id,
+      owner: None,
       state: State::default(),
       updated: false,
       world,
@@ -70,6 +81,66 @@ impl DefaultAmmoDump {
   }
 }
 
+impl DefaultAmmoDump {
+  // The colors of the teams with active tanks on the ammo dump
+  fn get_holders(&self) -> Vec<Color> {
+    let tank_operators = self.world.upgrade().unwrap().get_tank_operators();
+    let mut holders: Vec<Color> = Vec::new();
+    for tank_operator in tank_operators.borrow().iter() {
+      let tank = tank_operator.get_tank();
+      let tank = tank.borrow();
+      if !tank.is_active() || tank.is_burning() {
+        continue;
+      }
+      let center = tank.get_center();
+      if !self.circle.contains(center.x, center.y) {
+        continue;
+      }
+      let color: Color = tank.get_color();
+      if !holders.contains(&color) {
+        holders.push(color);
+      }
+    }
+    holders
+  }
+
+  fn update_capture(
+    &mut self,
+    time_delta: f64,
+  ) {
+    let holders: Vec<Color> = self.get_holders();
+    let contested: bool = holders.len() > 1;
+    if contested != self.contested {
+      self.contested = contested;
+      self.updated = true;
+    }
+    let holder: Option<Color> = if contested {
+      None
+    } else {
+      holders.first().copied()
+    };
+    if holder.is_none() || holder == self.owner {
+      if self.capturer.is_some() {
+        self.capturer = None;
+        self.capture_time = 0.;
+        self.updated = true;
+      }
+      return;
+    }
+    if holder != self.capturer {
+      self.capturer = holder;
+      self.capture_time = 0.;
+    }
+    self.capture_time += time_delta;
+    self.updated = true;
+    if self.capture_time >= AMMO_DUMP_CAPTURE_TIME_SECONDS {
+      self.owner = holder;
+      self.capturer = None;
+      self.capture_time = 0.;
+    }
+  }
+}
+
 impl AmmoDump for DefaultAmmoDump {
   fn set_ammo(
     &mut self,
@@ -93,6 +164,20 @@ impl AmmoDumpAccessor for DefaultAmmoDump {
     self.ammo
   }
 
+  fn get_capture_progress(&self) -> Option<(Color, f64)> {
+    self.capturer.map(|capturer| {
+      (capturer, self.capture_time / AMMO_DUMP_CAPTURE_TIME_SECONDS)
+    })
+  }
+
+  fn get_owner(&self) -> Option<Color> {
+    self.owner
+  }
+
+  fn is_contested(&self) -> bool {
+    self.contested
+  }
+
   fn is_nominal(&self) -> bool {
     matches!(self.state, State::Nominal(_))
   }
@@ -136,10 +221,21 @@ impl Model for DefaultAmmoDump {
           .make_explosion(explosion_circle, self.ammo);
         self.world.upgrade().unwrap().add_explosion(explosion);
         self.set_ammo(0.);
+        // The ammo dump is up for grabs again after it blows up
+        self.owner = None;
+        self.capturer = None;
+        self.capture_time = 0.;
+        self.contested = false;
       },
       State::Nominal(_) => {
+        self.update_capture(time_delta);
+        let ammo_growth_rate: f64 = if self.owner.is_some() {
+          AMMO_DUMP_OWNED_GROWTH_FACTOR * self.ammo_growth_rate
+        } else {
+          self.ammo_growth_rate
+        };
         let old_ammo: f64 = self.ammo;
-        let mut new_ammo = self.ammo + time_delta * self.ammo_growth_rate;
+        let mut new_ammo = self.ammo + time_delta * ammo_growth_rate;
         new_ammo = new_ammo.clamp(0., self.ammo_max);
         if new_ammo != old_ammo {
           self.set_ammo(new_ammo);
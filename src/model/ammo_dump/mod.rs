@@ -2,15 +2,16 @@
 //! -Ammo Dump traits for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-04-27
-//! - Updated: 2023-06-04
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
+use super::tank::Color;
 use super::{Damageable, Model, ModelAccessor};
 
 pub mod default;
@@ -25,5 +26,15 @@ pub trait AmmoDump: AmmoDumpAccessor + Damageable + Model {
 pub trait AmmoDumpAccessor: ModelAccessor {
   fn get_ammo(&self) -> f64;
 
+  // The team holding the ammo dump uncontested and the fraction of the
+  // capture time elapsed, if any
+  fn get_capture_progress(&self) -> Option<(Color, f64)>;
+
+  // The team that captured the ammo dump, if any
+  fn get_owner(&self) -> Option<Color>;
+
+  // True while active tanks of more than one team are on the ammo dump
+  fn is_contested(&self) -> bool;
+
   fn is_nominal(&self) -> bool;
 }
@@ -2,56 +2,65 @@
 //! - Default Bullet for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-05-10
-//! - Updated: 2023-09-04
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
-use super::{Bullet, BulletAccessor};
+use super::{Bullet, BulletAccessor, BulletConfig, BulletKind};
 use crate::constant::{
-  BULLET_DAMAGE, BULLET_RADIUS, BULLET_RANGE, BULLET_VELOCITY, BULLET_Z,
+  BULLET_RICOCHET_DAMAGE_FACTOR, BULLET_RICOCHET_RANGE_FACTOR, BULLET_Z,
 };
 use crate::model::{Model, ModelAccessor};
+use crate::world::World;
 use com_croftsoft_core::math::geom::circle::{Circle, CircleAccessor};
 use com_croftsoft_lib_role::PreparerMut;
+use core::f64::consts::{PI, TAU};
+use std::rc::Weak;
 
 pub struct DefaultBullet {
   active: bool,
+  bullet_config: BulletConfig,
+  // The current position
   circle: Circle,
-  distance: f64,
+  damage: f64,
+  // The current direction of travel
   heading: f64,
   id: usize,
-  origin_x: f64,
-  origin_y: f64,
+  range_remaining: f64,
   spent: bool,
   updated: bool,
+  world: Weak<dyn World>,
 }
 
 impl DefaultBullet {
   pub fn new(
+    bullet_config: BulletConfig,
     heading: f64,
     id: usize,
     origin_x: f64,
     origin_y: f64,
+    world: Weak<dyn World>,
   ) -> Self {
     Self {
       active: true,
+      bullet_config,
       circle: Circle {
         center_x: origin_x,
         center_y: origin_y,
-        radius: BULLET_RADIUS,
+        radius: bullet_config.radius,
       },
-      distance: 0.,
+      damage: bullet_config.damage,
       heading,
       id,
-      origin_x,
-      origin_y,
+      range_remaining: bullet_config.range,
       spent: false,
       updated: true,
+      world,
     }
   }
 }
@@ -60,6 +69,24 @@ impl Bullet for DefaultBullet {
   fn mark_spent(&mut self) {
     self.spent = true;
   }
+
+  fn ricochet(
+    &mut self,
+    circle: &Circle,
+  ) {
+    let normal_heading: f64 = (self.circle.center_y - circle.center_y)
+      .atan2(self.circle.center_x - circle.center_x);
+    self.heading = (2. * normal_heading + PI - self.heading).rem_euclid(TAU);
+    self.damage *= BULLET_RICOCHET_DAMAGE_FACTOR;
+    self.range_remaining *= BULLET_RICOCHET_RANGE_FACTOR;
+    // Moves the bullet back to the surface so that it does not strike again
+    let surface_distance: f64 = circle.radius + self.circle.radius;
+    self.circle.set_center(
+      circle.center_x + surface_distance * normal_heading.cos(),
+      circle.center_y + surface_distance * normal_heading.sin(),
+    );
+    self.updated = true;
+  }
 }
 
 impl BulletAccessor for DefaultBullet {
@@ -67,9 +94,25 @@ impl BulletAccessor for DefaultBullet {
     if !self.active || self.spent {
       0.
     } else {
-      BULLET_DAMAGE
+      self.damage
     }
   }
+
+  fn get_heading(&self) -> f64 {
+    self.heading
+  }
+
+  fn get_kind(&self) -> BulletKind {
+    self.bullet_config.kind
+  }
+
+  fn get_range_remaining(&self) -> f64 {
+    self.range_remaining.max(0.)
+  }
+
+  fn get_velocity(&self) -> f64 {
+    self.bullet_config.velocity
+  }
 }
 
 impl Model for DefaultBullet {
@@ -86,13 +129,21 @@ impl Model for DefaultBullet {
       return;
     }
     self.updated = true;
-    self.distance += time_delta * BULLET_VELOCITY;
-    if self.distance > BULLET_RANGE || self.distance < 0. {
+    let step: f64 = time_delta * self.bullet_config.velocity;
+    let bullet_drag: f64 = self
+      .world
+      .upgrade()
+      .unwrap()
+      .get_terrain()
+      .borrow()
+      .get_bullet_drag(&self.circle.get_center_point_2dd());
+    self.range_remaining -= bullet_drag * step;
+    if self.range_remaining < 0. {
       self.active = false;
       return;
     }
-    let center_x = self.origin_x + self.distance * self.heading.cos();
-    let center_y = self.origin_y + self.distance * self.heading.sin();
+    let center_x = self.circle.center_x + step * self.heading.cos();
+    let center_y = self.circle.center_y + step * self.heading.sin();
     self.circle.set_center(center_x, center_y);
   }
 }
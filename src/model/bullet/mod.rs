@@ -2,23 +2,59 @@
 //! - Bullet traits for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-05-10
-//! - Updated: 2023-06-03
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
 use crate::model::{Model, ModelAccessor};
+use com_croftsoft_core::math::geom::circle::Circle;
 
 pub mod default;
 
+// The ballistic parameters of a bullet
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BulletConfig {
+  pub damage: f64,
+  pub kind: BulletKind,
+  pub radius: f64,
+  pub range: f64,
+  pub velocity: f64,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum BulletKind {
+  // Passes through obstacles
+  ArmorPiercing,
+  // Explodes on impact
+  HighExplosive,
+  #[default]
+  Standard,
+}
+
 pub trait Bullet: BulletAccessor + Model {
   fn mark_spent(&mut self);
+
+  // Reflects the bullet off the surface of the circle with reduced damage and
+  // range remaining
+  fn ricochet(
+    &mut self,
+    circle: &Circle,
+  );
 }
 
 pub trait BulletAccessor: ModelAccessor {
   fn get_damage(&self) -> f64;
+
+  fn get_heading(&self) -> f64;
+
+  fn get_kind(&self) -> BulletKind;
+
+  fn get_range_remaining(&self) -> f64;
+
+  fn get_velocity(&self) -> f64;
 }
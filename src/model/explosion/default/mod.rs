@@ -2,27 +2,30 @@
 //! - Default Explosion for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-05-15
-//! - Updated: 2023-09-04
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
 use self::state::State;
-use super::{Explosion, ExplosionAccessor};
+use super::{Explosion, ExplosionAccessor, ExplosionTarget};
 use crate::constant::{EXPLOSION_RADIUS_DECAY_RATE, EXPLOSION_RADIUS_MINIMUM};
 use crate::model::{Model, ModelAccessor};
 use com_croftsoft_core::math::geom::circle::{Circle, CircleAccessor};
 use com_croftsoft_lib_role::PreparerMut;
+use std::collections::HashSet;
 
 pub mod state;
 
 pub struct DefaultExplosion {
   circle: Circle,
   damage: f64,
+  // The targets already damaged so that they are not damaged twice
+  damaged_targets: HashSet<ExplosionTarget>,
   id: usize,
   state: State,
   updated: bool,
@@ -37,6 +40,7 @@ impl DefaultExplosion {
     Self {
       circle,
       damage,
+      damaged_targets: HashSet::new(),
       id,
       state: State::default(),
       updated: false,
@@ -44,7 +48,14 @@ impl DefaultExplosion {
   }
 }
 
-impl Explosion for DefaultExplosion {}
+impl Explosion for DefaultExplosion {
+  fn mark_damaged(
+    &mut self,
+    explosion_target: ExplosionTarget,
+  ) {
+    self.damaged_targets.insert(explosion_target);
+  }
+}
 
 impl ExplosionAccessor for DefaultExplosion {
   fn get_damage(&self) -> f64 {
@@ -53,6 +64,13 @@ impl ExplosionAccessor for DefaultExplosion {
       _ => 0.,
     }
   }
+
+  fn has_damaged(
+    &self,
+    explosion_target: ExplosionTarget,
+  ) -> bool {
+    self.damaged_targets.contains(&explosion_target)
+  }
 }
 
 impl Model for DefaultExplosion {
@@ -2,10 +2,10 @@
 //! - Explosion traits for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-05-15
-//! - Updated: 2023-06-03
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -15,8 +15,29 @@ use crate::model::{Model, ModelAccessor};
 
 pub mod default;
 
-pub trait Explosion: ExplosionAccessor + Model {}
+pub trait Explosion: ExplosionAccessor + Model {
+  // Records that the explosion has dealt its damage to the target
+  fn mark_damaged(
+    &mut self,
+    explosion_target: ExplosionTarget,
+  );
+}
 
 pub trait ExplosionAccessor: ModelAccessor {
   fn get_damage(&self) -> f64;
+
+  fn has_damaged(
+    &self,
+    explosion_target: ExplosionTarget,
+  ) -> bool;
+}
+
+// A model damaged by an explosion, identified by its type and ID
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ExplosionTarget {
+  AmmoDump(usize),
+  Mine(usize),
+  Obstacle(usize),
+  Tank(usize),
+  Wreck(usize),
 }
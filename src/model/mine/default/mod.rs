@@ -0,0 +1,194 @@
+// =============================================================================
+//! - Default Mine for CroftSoft Mars
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use self::state::State;
+use super::{Mine, MineAccessor};
+use crate::constant::{
+  MINE_EXPLOSION_DAMAGE, MINE_EXPLOSION_RADIUS, MINE_RADIUS, MINE_Z,
+};
+use crate::model::tank::Color;
+use crate::model::{Damageable, Model, ModelAccessor};
+use crate::world::factory::WorldFactory;
+use crate::world::World;
+use com_croftsoft_core::math::geom::circle::{Circle, CircleAccessor};
+use com_croftsoft_lib_role::PreparerMut;
+use std::rc::Weak;
+
+pub mod state;
+
+pub struct DefaultMine {
+  circle: Circle,
+  color: Color,
+  factory: Weak<dyn WorldFactory>,
+  id: usize,
+  state: State,
+  updated: bool,
+  world: Weak<dyn World>,
+}
+
+impl DefaultMine {
+  // True if an enemy tank overlaps the mine
+  fn is_triggered(&self) -> bool {
+    self
+      .world
+      .upgrade()
+      .unwrap()
+      .get_tank_operators()
+      .borrow()
+      .iter()
+      .any(|tank_operator| {
+        let tank = tank_operator.get_tank();
+        let tank = tank.borrow();
+        tank.is_active()
+          && tank.get_color() != self.color
+          && tank.intersects_circle(&self.circle)
+      })
+  }
+
+  pub fn new(
+    center_x: f64,
+    center_y: f64,
+    color: Color,
+    factory: Weak<dyn WorldFactory>,
+    id: usize,
+    world: Weak<dyn World>,
+  ) -> Self {
+    let circle = Circle {
+      center_x,
+      center_y,
+      radius: MINE_RADIUS,
+    };
+    Self {
+      circle,
+      color,
+      factory,
+      id,
+      state: State::default(),
+      updated: true,
+      world,
+    }
+  }
+}
+
+impl Damageable for DefaultMine {
+  fn add_damage(
+    &mut self,
+    damage: f64,
+  ) {
+    if damage <= 0. {
+      return;
+    }
+    match &self.state {
+      State::Armed(state_operator) => {
+        self.state = state_operator.to_detonating();
+        self.updated = true;
+      },
+      State::Arming(state_operator) => {
+        self.state = state_operator.to_detonating();
+        self.updated = true;
+      },
+      State::Detonating(_) | State::Inactive => (),
+    }
+  }
+}
+
+impl Mine for DefaultMine {}
+
+impl MineAccessor for DefaultMine {
+  fn get_color(&self) -> Color {
+    self.color
+  }
+
+  fn is_armed(&self) -> bool {
+    matches!(self.state, State::Armed(_))
+  }
+}
+
+impl Model for DefaultMine {
+  fn update(
+    &mut self,
+    time_delta: f64,
+  ) {
+    let triggered: bool = self.is_armed() && self.is_triggered();
+    match &mut self.state {
+      State::Armed(state_operator) => {
+        if triggered {
+          self.state = state_operator.to_detonating();
+          self.updated = true;
+        }
+      },
+      State::Arming(state_operator) => {
+        if state_operator.done_arming(time_delta) {
+          self.state = state_operator.to_armed();
+          self.updated = true;
+        }
+      },
+      State::Detonating(state_operator) => {
+        self.state = state_operator.to_inactive();
+        self.updated = true;
+        let mut explosion_circle: Circle = self.circle;
+        explosion_circle.radius = MINE_EXPLOSION_RADIUS;
+        let explosion = self
+          .factory
+          .upgrade()
+          .unwrap()
+          .make_explosion(explosion_circle, MINE_EXPLOSION_DAMAGE);
+        self.world.upgrade().unwrap().add_explosion(explosion);
+      },
+      State::Inactive => (),
+    }
+  }
+}
+
+impl ModelAccessor for DefaultMine {
+  fn contains(
+    &self,
+    x: f64,
+    y: f64,
+  ) -> bool {
+    self.circle.contains(x, y)
+  }
+
+  fn get_circle(&self) -> Circle {
+    self.circle
+  }
+
+  fn get_id(&self) -> usize {
+    self.id
+  }
+
+  fn get_z(&self) -> f64 {
+    MINE_Z
+  }
+
+  fn intersects_circle(
+    &self,
+    circle: &dyn CircleAccessor,
+  ) -> bool {
+    self.circle.intersects_circle(circle)
+  }
+
+  fn is_active(&self) -> bool {
+    !matches!(self.state, State::Inactive)
+  }
+
+  fn is_updated(&self) -> bool {
+    self.updated
+  }
+}
+
+impl PreparerMut for DefaultMine {
+  fn prepare(&mut self) {
+    self.updated = false;
+  }
+}
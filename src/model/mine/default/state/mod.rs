@@ -0,0 +1,79 @@
+// =============================================================================
+//! - Default Mine State for CroftSoft Mars
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constant::MINE_ARMING_TIME_SECONDS;
+
+pub struct ArmedData;
+
+pub struct ArmingData {
+  arming_time_elapsed_seconds: f64,
+}
+
+pub struct DetonatingData;
+
+pub struct StateOperator<D> {
+  data: D,
+}
+
+impl StateOperator<ArmedData> {
+  pub fn to_detonating(&self) -> State {
+    State::Detonating(StateOperator {
+      data: DetonatingData {},
+    })
+  }
+}
+
+impl StateOperator<ArmingData> {
+  pub fn done_arming(
+    &mut self,
+    time_delta: f64,
+  ) -> bool {
+    self.data.arming_time_elapsed_seconds += time_delta;
+    self.data.arming_time_elapsed_seconds >= MINE_ARMING_TIME_SECONDS
+  }
+
+  pub fn to_armed(&self) -> State {
+    State::Armed(StateOperator {
+      data: ArmedData {},
+    })
+  }
+
+  pub fn to_detonating(&self) -> State {
+    State::Detonating(StateOperator {
+      data: DetonatingData {},
+    })
+  }
+}
+
+impl StateOperator<DetonatingData> {
+  pub fn to_inactive(&self) -> State {
+    State::Inactive
+  }
+}
+
+pub enum State {
+  Armed(StateOperator<ArmedData>),
+  Arming(StateOperator<ArmingData>),
+  Detonating(StateOperator<DetonatingData>),
+  Inactive,
+}
+
+impl Default for State {
+  fn default() -> Self {
+    State::Arming(StateOperator {
+      data: ArmingData {
+        arming_time_elapsed_seconds: 0.,
+      },
+    })
+  }
+}
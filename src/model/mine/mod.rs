@@ -0,0 +1,26 @@
+// =============================================================================
+//! - Mine for CroftSoft Mars
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use super::tank::Color;
+use super::{Damageable, Model, ModelAccessor};
+
+pub mod default;
+
+pub trait Mine: Damageable + MineAccessor + Model {}
+
+pub trait MineAccessor: ModelAccessor {
+  // The color of the team that laid the mine
+  fn get_color(&self) -> Color;
+
+  fn is_armed(&self) -> bool;
+}
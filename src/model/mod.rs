@@ -2,10 +2,10 @@
 //! - Model traits for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-06-03
-//! - Updated: 2023-09-04
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -17,7 +17,9 @@ use com_croftsoft_lib_role::PreparerMut;
 pub mod ammo_dump;
 pub mod bullet;
 pub mod explosion;
+pub mod mine;
 pub mod obstacle;
+pub mod shell;
 pub mod tank;
 
 pub trait Damageable {
@@ -57,3 +59,13 @@ pub trait ModelAccessor {
 
   fn is_updated(&self) -> bool;
 }
+
+// A model that can be pushed, such as by the blast of an explosion
+pub trait Pushable {
+  // Adds the change in velocity in meters per second
+  fn add_impulse(
+    &mut self,
+    impulse_x: f64,
+    impulse_y: f64,
+  );
+}
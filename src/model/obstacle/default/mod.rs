@@ -2,10 +2,10 @@
 //! - Obstacle state for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-03-12
-//! - Updated: 2023-09-04
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -13,14 +13,18 @@
 
 use super::{Obstacle, ObstacleAccessor};
 use crate::constant::{
-  OBSTACLE_JERK_MAGNITUDE_MAX, OBSTACLE_SPEED_MAX, OBSTACLE_Z,
+  OBSTACLE_FRAGMENTATION_DAMAGE_MIN, OBSTACLE_FRAGMENTATION_RADIUS_MIN,
+  OBSTACLE_FRAGMENT_COUNT_MAX, OBSTACLE_FRAGMENT_SPEED, OBSTACLE_SPEED_MAX,
+  OBSTACLE_Z,
 };
-use crate::model::{Damageable, Model, ModelAccessor};
+use crate::model::{Damageable, Model, ModelAccessor, Pushable};
+use crate::world::factory::WorldFactory;
 use crate::world::World;
 use com_croftsoft_core::math::geom::circle::{Circle, CircleAccessor};
 use com_croftsoft_core::math::geom::point_2dd::Point2DD;
 use com_croftsoft_core::math::geom::rectangle::Rectangle;
 use com_croftsoft_lib_role::PreparerMut;
+use core::f64::consts::TAU;
 use rand::rngs::ThreadRng;
 use rand::Rng;
 use std::rc::Weak;
@@ -29,8 +33,13 @@ pub struct DefaultObstacle {
   pub active: bool,
   pub circle: Circle,
   pub drift_bounds: Rectangle,
+  factory: Weak<dyn WorldFactory>,
+  // Hit hard enough to split into fragments on the next update
+  fragmenting: bool,
   // TODO: make this read-only
   pub id: usize,
+  // Maximum random change in velocity per second; zero disables the drift
+  jerk_magnitude_max: f64,
   pub radius_min: f64,
   pub updated: bool,
   pub velocity_x: f64,
@@ -42,7 +51,9 @@ impl DefaultObstacle {
   pub fn new(
     circle: Circle,
     drift_bounds: Rectangle,
+    factory: Weak<dyn WorldFactory>,
     id: usize,
+    jerk_magnitude_max: f64,
     radius_min: f64,
     world: Weak<dyn World>,
   ) -> Self {
@@ -50,7 +61,10 @@ impl DefaultObstacle {
       active: true,
       circle,
       drift_bounds,
+      factory,
+      fragmenting: false,
       id,
+      jerk_magnitude_max,
       radius_min,
       updated: false,
       velocity_x: 0.,
@@ -60,6 +74,97 @@ impl DefaultObstacle {
   }
 }
 
+impl DefaultObstacle {
+  // Bounces elastically off the other obstacles that the circle would overlap
+  // while approaching them, with mass proportional to area.  Wrecks do not
+  // move.  Returns true if there was a collision.
+  fn collide_with_obstacles(
+    &mut self,
+    circle: &Circle,
+  ) -> bool {
+    let mut collided: bool = false;
+    let inverse_mass: f64 = 1. / (circle.radius * circle.radius);
+    let obstacles = self.world.upgrade().unwrap().get_obstacles();
+    for other in obstacles.borrow_mut().iter_mut() {
+      if !other.is_active() || !other.intersects_circle(circle) {
+        continue;
+      }
+      let other_circle: Circle = other.get_circle();
+      let delta_x: f64 = circle.center_x - other_circle.center_x;
+      let delta_y: f64 = circle.center_y - other_circle.center_y;
+      let distance: f64 = delta_x.hypot(delta_y);
+      if distance <= 0. {
+        continue;
+      }
+      let normal_x: f64 = delta_x / distance;
+      let normal_y: f64 = delta_y / distance;
+      let normal_velocity: f64 = (self.velocity_x - other.get_velocity_x())
+        * normal_x
+        + (self.velocity_y - other.get_velocity_y()) * normal_y;
+      if normal_velocity >= 0. {
+        // Already separating
+        continue;
+      }
+      let other_inverse_mass: f64 = if other.is_wreck() {
+        0.
+      } else {
+        1. / (other_circle.radius * other_circle.radius)
+      };
+      let impulse: f64 =
+        -2. * normal_velocity / (inverse_mass + other_inverse_mass);
+      self.velocity_x += impulse * inverse_mass * normal_x;
+      self.velocity_y += impulse * inverse_mass * normal_y;
+      other.add_impulse(
+        -impulse * other_inverse_mass * normal_x,
+        -impulse * other_inverse_mass * normal_y,
+      );
+      collided = true;
+    }
+    collided
+  }
+
+  // Replaces the obstacle with two or three smaller fragments of the same
+  // total area flying outward from its center
+  fn fragment(&mut self) {
+    self.active = false;
+    self.updated = true;
+    let mut thread_rng: ThreadRng = rand::thread_rng();
+    let mut fragment_count: usize =
+      thread_rng.gen_range(2..=OBSTACLE_FRAGMENT_COUNT_MAX);
+    while fragment_count > 2
+      && self.circle.radius / (fragment_count as f64).sqrt() < self.radius_min
+    {
+      fragment_count -= 1;
+    }
+    let fragment_radius: f64 =
+      self.circle.radius / (fragment_count as f64).sqrt();
+    let factory = self.factory.upgrade().unwrap();
+    let world = self.world.upgrade().unwrap();
+    let heading_offset: f64 = thread_rng.gen_range(0.0..TAU);
+    for index in 0..fragment_count {
+      let heading: f64 =
+        heading_offset + TAU * index as f64 / fragment_count as f64;
+      let offset: f64 = self.circle.radius - fragment_radius;
+      let circle = Circle {
+        center_x: self.circle.center_x + offset * heading.cos(),
+        center_y: self.circle.center_y + offset * heading.sin(),
+        radius: fragment_radius,
+      };
+      let mut fragment: Box<dyn Obstacle> = factory.make_obstacle(
+        circle,
+        self.drift_bounds,
+        self.factory.clone(),
+        self.world.clone(),
+      );
+      fragment.add_impulse(
+        self.velocity_x + OBSTACLE_FRAGMENT_SPEED * heading.cos(),
+        self.velocity_y + OBSTACLE_FRAGMENT_SPEED * heading.sin(),
+      );
+      world.add_obstacle(fragment);
+    }
+  }
+}
+
 impl Damageable for DefaultObstacle {
   fn add_damage(
     &mut self,
@@ -74,6 +179,12 @@ impl Damageable for DefaultObstacle {
       self.active = false;
     } else {
       self.circle.radius = radius;
+      // Large obstacles hit hard split apart
+      if damage >= OBSTACLE_FRAGMENTATION_DAMAGE_MIN
+        && radius >= OBSTACLE_FRAGMENTATION_RADIUS_MIN
+      {
+        self.fragmenting = true;
+      }
     }
   }
 }
@@ -86,18 +197,24 @@ impl Model for DefaultObstacle {
     if !self.active {
       return;
     }
-    let mut thread_rng: ThreadRng = rand::thread_rng();
-    let velocity_x_delta: f64 = thread_rng.gen_range(-1.0..=1.0)
-      * OBSTACLE_JERK_MAGNITUDE_MAX
-      * time_delta;
-    let velocity_y_delta: f64 = thread_rng.gen_range(-1.0..=1.0)
-      * OBSTACLE_JERK_MAGNITUDE_MAX
-      * time_delta;
-    let mut velocity_x: f64 = self.velocity_x + velocity_x_delta;
-    let mut velocity_y: f64 = self.velocity_y + velocity_y_delta;
-    // TODO: clamp speed of vector instead of individual axis components
-    velocity_x = velocity_x.clamp(-OBSTACLE_SPEED_MAX, OBSTACLE_SPEED_MAX);
-    velocity_y = velocity_y.clamp(-OBSTACLE_SPEED_MAX, OBSTACLE_SPEED_MAX);
+    if self.fragmenting {
+      self.fragment();
+      return;
+    }
+    let mut velocity_x: f64 = self.velocity_x;
+    let mut velocity_y: f64 = self.velocity_y;
+    if self.jerk_magnitude_max > 0. {
+      let mut thread_rng: ThreadRng = rand::thread_rng();
+      velocity_x +=
+        thread_rng.gen_range(-1.0..=1.0) * self.jerk_magnitude_max * time_delta;
+      velocity_y +=
+        thread_rng.gen_range(-1.0..=1.0) * self.jerk_magnitude_max * time_delta;
+    }
+    let speed: f64 = velocity_x.hypot(velocity_y);
+    if speed > OBSTACLE_SPEED_MAX {
+      velocity_x *= OBSTACLE_SPEED_MAX / speed;
+      velocity_y *= OBSTACLE_SPEED_MAX / speed;
+    }
     let distance_x_delta: f64 = velocity_x * time_delta;
     let distance_y_delta: f64 = velocity_y * time_delta;
     let Circle {
@@ -127,36 +244,29 @@ impl Model for DefaultObstacle {
     }
     self.velocity_x = velocity_x;
     self.velocity_y = velocity_y;
-    if new_center_x != old_center_x || new_center_y != old_center_y {
-      if self
-        .world
-        .upgrade()
-        .unwrap()
-        .is_blocked_by_impassable(&self.circle)
-      {
-        self.circle.center_x = new_center_x;
-        self.circle.center_y = new_center_y;
-        // TODO: updated event
-      } else {
-        let new_circle = Circle {
-          center_x: new_center_x,
-          center_y: new_center_y,
-          radius,
-        };
-        if !self
-          .world
-          .upgrade()
-          .unwrap()
-          .is_blocked_by_impassable(&new_circle)
-        {
-          self.circle.center_x = new_center_x;
-          self.circle.center_y = new_center_y;
-        } else {
-          self.velocity_x = 0.;
-          self.velocity_y = 0.;
-          // TODO: updated event
-        }
-      }
+    if new_center_x == old_center_x && new_center_y == old_center_y {
+      return;
+    }
+    let new_circle = Circle {
+      center_x: new_center_x,
+      center_y: new_center_y,
+      radius,
+    };
+    if self.collide_with_obstacles(&new_circle) {
+      // Bounced instead of moving into the other obstacle
+      self.updated = true;
+      return;
+    }
+    let world = self.world.upgrade().unwrap();
+    if world.is_blocked_by_tank(&self.circle)
+      || !world.is_blocked_by_tank(&new_circle)
+    {
+      self.circle.center_x = new_center_x;
+      self.circle.center_y = new_center_y;
+      self.updated = true;
+    } else {
+      self.velocity_x = 0.;
+      self.velocity_y = 0.;
     }
   }
 }
@@ -204,6 +314,18 @@ impl ObstacleAccessor for DefaultObstacle {
   fn get_center(&self) -> Point2DD {
     self.circle.get_center_point_2dd()
   }
+
+  fn get_velocity_x(&self) -> f64 {
+    self.velocity_x
+  }
+
+  fn get_velocity_y(&self) -> f64 {
+    self.velocity_y
+  }
+
+  fn is_wreck(&self) -> bool {
+    false
+  }
 }
 
 impl PreparerMut for DefaultObstacle {
@@ -211,3 +333,14 @@ impl PreparerMut for DefaultObstacle {
     self.updated = false;
   }
 }
+
+impl Pushable for DefaultObstacle {
+  fn add_impulse(
+    &mut self,
+    impulse_x: f64,
+    impulse_y: f64,
+  ) {
+    self.velocity_x += impulse_x;
+    self.velocity_y += impulse_y;
+  }
+}
@@ -2,22 +2,30 @@
 //! - Obstacle traits for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-03-27
-//! - Updated: 2023-07-09
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
-use super::{Damageable, Model, ModelAccessor};
+use super::{Damageable, Model, ModelAccessor, Pushable};
 use com_croftsoft_core::math::geom::point_2dd::Point2DD;
 
 pub mod default;
+pub mod wreck;
 
-pub trait Obstacle: Damageable + Model + ObstacleAccessor {}
+pub trait Obstacle: Damageable + Model + ObstacleAccessor + Pushable {}
 
 pub trait ObstacleAccessor: ModelAccessor {
   fn get_center(&self) -> Point2DD;
+
+  fn get_velocity_x(&self) -> f64;
+
+  fn get_velocity_y(&self) -> f64;
+
+  // True if the obstacle is the remains of a destroyed tank
+  fn is_wreck(&self) -> bool;
 }
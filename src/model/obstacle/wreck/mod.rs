@@ -0,0 +1,185 @@
+// =============================================================================
+//! - Wreck Obstacle for CroftSoft Mars
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use super::{Obstacle, ObstacleAccessor};
+use crate::constant::{
+  OBSTACLE_RADIUS_MIN, OBSTACLE_Z, WRECK_BURN_OUT_TIME_SECONDS,
+  WRECK_EXPLOSION_DAMAGE, WRECK_EXPLOSION_RADIUS,
+};
+use crate::model::{Damageable, Model, ModelAccessor, Pushable};
+use crate::world::factory::WorldFactory;
+use crate::world::World;
+use com_croftsoft_core::math::geom::circle::{Circle, CircleAccessor};
+use com_croftsoft_core::math::geom::point_2dd::Point2DD;
+use com_croftsoft_lib_role::PreparerMut;
+use std::rc::Weak;
+
+// The remains of a destroyed tank.  It stays put, shrinks when shot, and
+// disappears when it burns out or is destroyed.
+pub struct WreckObstacle {
+  active: bool,
+  burn_out_time_remaining: f64,
+  circle: Circle,
+  // Destroyed by damage and waiting to explode on the next update
+  destroyed: bool,
+  factory: Weak<dyn WorldFactory>,
+  id: usize,
+  updated: bool,
+  world: Weak<dyn World>,
+}
+
+impl WreckObstacle {
+  pub fn new(
+    circle: Circle,
+    factory: Weak<dyn WorldFactory>,
+    id: usize,
+    world: Weak<dyn World>,
+  ) -> Self {
+    Self {
+      active: true,
+      burn_out_time_remaining: WRECK_BURN_OUT_TIME_SECONDS,
+      circle,
+      destroyed: false,
+      factory,
+      id,
+      updated: true,
+      world,
+    }
+  }
+}
+
+impl Damageable for WreckObstacle {
+  fn add_damage(
+    &mut self,
+    damage: f64,
+  ) {
+    if !self.active || self.destroyed || damage <= 0. {
+      return;
+    }
+    self.updated = true;
+    let radius = self.circle.radius - damage;
+    if radius < OBSTACLE_RADIUS_MIN {
+      self.destroyed = true;
+    } else {
+      self.circle.radius = radius;
+    }
+  }
+}
+
+impl Model for WreckObstacle {
+  fn update(
+    &mut self,
+    time_delta: f64,
+  ) {
+    if !self.active {
+      return;
+    }
+    if self.destroyed {
+      self.active = false;
+      self.updated = true;
+      // An explosion damage of zero disables the final explosion
+      if WRECK_EXPLOSION_DAMAGE > 0. {
+        let explosion_circle = Circle {
+          center_x: self.circle.center_x,
+          center_y: self.circle.center_y,
+          radius: WRECK_EXPLOSION_RADIUS,
+        };
+        let explosion = self
+          .factory
+          .upgrade()
+          .unwrap()
+          .make_explosion(explosion_circle, WRECK_EXPLOSION_DAMAGE);
+        self.world.upgrade().unwrap().add_explosion(explosion);
+      }
+      return;
+    }
+    self.burn_out_time_remaining -= time_delta;
+    if self.burn_out_time_remaining <= 0. {
+      self.active = false;
+      self.updated = true;
+    }
+  }
+}
+
+impl ModelAccessor for WreckObstacle {
+  fn contains(
+    &self,
+    x: f64,
+    y: f64,
+  ) -> bool {
+    self.circle.contains(x, y)
+  }
+
+  fn get_circle(&self) -> Circle {
+    self.circle
+  }
+
+  fn get_id(&self) -> usize {
+    self.id
+  }
+
+  fn get_z(&self) -> f64 {
+    OBSTACLE_Z
+  }
+
+  fn intersects_circle(
+    &self,
+    circle: &dyn CircleAccessor,
+  ) -> bool {
+    self.circle.intersects_circle(circle)
+  }
+
+  fn is_active(&self) -> bool {
+    self.active
+  }
+
+  fn is_updated(&self) -> bool {
+    self.updated
+  }
+}
+
+impl Obstacle for WreckObstacle {}
+
+impl ObstacleAccessor for WreckObstacle {
+  fn get_center(&self) -> Point2DD {
+    self.circle.get_center_point_2dd()
+  }
+
+  fn get_velocity_x(&self) -> f64 {
+    0.
+  }
+
+  fn get_velocity_y(&self) -> f64 {
+    0.
+  }
+
+  fn is_wreck(&self) -> bool {
+    true
+  }
+}
+
+impl PreparerMut for WreckObstacle {
+  fn prepare(&mut self) {
+    self.updated = false;
+  }
+}
+
+// Too heavy to be pushed
+impl Pushable for WreckObstacle {
+  fn add_impulse(
+    &mut self,
+    _impulse_x: f64,
+    _impulse_y: f64,
+  ) {
+  }
+}
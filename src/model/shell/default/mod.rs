@@ -0,0 +1,160 @@
+// =============================================================================
+//! - Default Shell for CroftSoft Mars
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use super::{Shell, ShellAccessor};
+use crate::constant::{
+  SHELL_APEX_FRACTION, SHELL_DAMAGE, SHELL_EXPLOSION_RADIUS, SHELL_RADIUS,
+  SHELL_VELOCITY, SHELL_Z,
+};
+use crate::model::{Model, ModelAccessor};
+use crate::world::factory::WorldFactory;
+use crate::world::World;
+use com_croftsoft_core::math::geom::circle::{Circle, CircleAccessor};
+use com_croftsoft_core::math::geom::point_2dd::Point2DD;
+use com_croftsoft_lib_role::PreparerMut;
+use std::rc::Weak;
+
+pub struct DefaultShell {
+  active: bool,
+  altitude: f64,
+  // The position of the shell projected onto the ground
+  circle: Circle,
+  factory: Weak<dyn WorldFactory>,
+  flight_time: f64,
+  flight_time_elapsed: f64,
+  id: usize,
+  origin: Point2DD,
+  target: Point2DD,
+  updated: bool,
+  world: Weak<dyn World>,
+}
+
+impl DefaultShell {
+  pub fn new(
+    factory: Weak<dyn WorldFactory>,
+    id: usize,
+    origin: Point2DD,
+    target: Point2DD,
+    world: Weak<dyn World>,
+  ) -> Self {
+    let circle = Circle {
+      center_x: origin.x,
+      center_y: origin.y,
+      radius: SHELL_RADIUS,
+    };
+    Self {
+      active: true,
+      altitude: 0.,
+      circle,
+      factory,
+      flight_time: origin.distance_to(&target) / SHELL_VELOCITY,
+      flight_time_elapsed: 0.,
+      id,
+      origin,
+      target,
+      updated: true,
+      world,
+    }
+  }
+}
+
+impl Shell for DefaultShell {}
+
+impl ShellAccessor for DefaultShell {
+  fn get_altitude(&self) -> f64 {
+    self.altitude
+  }
+
+  fn get_landing_circle(&self) -> Circle {
+    Circle {
+      center_x: self.target.x,
+      center_y: self.target.y,
+      radius: SHELL_EXPLOSION_RADIUS,
+    }
+  }
+}
+
+impl Model for DefaultShell {
+  fn update(
+    &mut self,
+    time_delta: f64,
+  ) {
+    if !self.active {
+      return;
+    }
+    self.updated = true;
+    self.flight_time_elapsed += time_delta;
+    if self.flight_time_elapsed >= self.flight_time {
+      self.active = false;
+      let explosion = self
+        .factory
+        .upgrade()
+        .unwrap()
+        .make_explosion(self.get_landing_circle(), SHELL_DAMAGE);
+      self.world.upgrade().unwrap().add_explosion(explosion);
+      return;
+    }
+    // Fraction of the flight completed
+    let progress: f64 = self.flight_time_elapsed / self.flight_time;
+    self.circle.set_center(
+      self.origin.x + progress * (self.target.x - self.origin.x),
+      self.origin.y + progress * (self.target.y - self.origin.y),
+    );
+    // A parabola that peaks at the midpoint of the flight
+    let apex: f64 = SHELL_APEX_FRACTION * self.origin.distance_to(&self.target);
+    self.altitude = 4. * apex * progress * (1. - progress);
+  }
+}
+
+impl ModelAccessor for DefaultShell {
+  fn contains(
+    &self,
+    x: f64,
+    y: f64,
+  ) -> bool {
+    self.circle.contains(x, y)
+  }
+
+  fn get_circle(&self) -> Circle {
+    self.circle
+  }
+
+  fn get_id(&self) -> usize {
+    self.id
+  }
+
+  fn get_z(&self) -> f64 {
+    SHELL_Z
+  }
+
+  fn intersects_circle(
+    &self,
+    circle: &dyn CircleAccessor,
+  ) -> bool {
+    self.circle.intersects_circle(circle)
+  }
+
+  fn is_active(&self) -> bool {
+    self.active
+  }
+
+  fn is_updated(&self) -> bool {
+    self.updated
+  }
+}
+
+impl PreparerMut for DefaultShell {
+  fn prepare(&mut self) {
+    self.updated = false;
+  }
+}
@@ -0,0 +1,28 @@
+// =============================================================================
+//! - Shell for CroftSoft Mars
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use super::{Model, ModelAccessor};
+use com_croftsoft_core::math::geom::circle::Circle;
+
+pub mod default;
+
+// An artillery shell that arcs over obstacles to a target point
+pub trait Shell: Model + ShellAccessor {}
+
+pub trait ShellAccessor: ModelAccessor {
+  // Height of the shell above the ground
+  fn get_altitude(&self) -> f64;
+
+  // The area the explosion will cover when the shell lands
+  fn get_landing_circle(&self) -> Circle;
+}
@@ -0,0 +1,137 @@
+// =============================================================================
+//! - Tank Archetype for CroftSoft Mars
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constant::{
+  BULLET_DAMAGE, BULLET_RADIUS, BULLET_RANGE, BULLET_VELOCITY,
+  TANK_ACCELERATION_METERS_PER_SECOND_SQUARED, TANK_AMMO_MAX,
+  TANK_BODY_ROTATION_SPEED_RADIANS_PER_SECOND,
+  TANK_DECELERATION_METERS_PER_SECOND_SQUARED, TANK_RADIUS,
+  TANK_RELOAD_TIME_SECONDS, TANK_SPEED_METERS_PER_SECOND,
+  TANK_TURRET_ROTATION_SPEED_RADIANS_PER_SECOND,
+};
+use crate::model::bullet::{BulletConfig, BulletKind};
+use core::f64::consts::TAU;
+
+// The physical and weapon parameters of a class of tank.  The ammo, bullet,
+// and reload time are those of the standard weapon.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TankArchetype {
+  pub acceleration: f64,
+  pub ammo_max: usize,
+  // Divides the damage received
+  pub armor: f64,
+  pub body_rotation_speed: f64,
+  pub bullet: BulletConfig,
+  pub deceleration: f64,
+  // Can lob shells over obstacles
+  pub indirect_fire: bool,
+  pub radius: f64,
+  pub reload_time: f64,
+  pub speed: f64,
+  pub turret_rotation_speed: f64,
+  // Maximum turret rotation to either side of the body heading, if any
+  pub turret_traverse_limit: Option<f64>,
+}
+
+impl TankArchetype {
+  // Slow long range gun with a limited traverse
+  pub const ARTILLERY: Self = Self {
+    acceleration: 20.,
+    ammo_max: 15,
+    armor: 0.75,
+    body_rotation_speed: TAU / 8.,
+    bullet: BulletConfig {
+      damage: 2.,
+      kind: BulletKind::Standard,
+      radius: 5.,
+      range: 400.,
+      velocity: 60.,
+    },
+    deceleration: 50.,
+    indirect_fire: true,
+    radius: 28.,
+    reload_time: 5.,
+    speed: 22.,
+    turret_rotation_speed: TAU / 6.,
+    turret_traverse_limit: Some(TAU / 8.),
+  };
+
+  pub const HEAVY: Self = Self {
+    acceleration: 15.,
+    ammo_max: 40,
+    armor: 2.,
+    body_rotation_speed: TAU / 10.,
+    bullet: BulletConfig {
+      damage: 1.5,
+      kind: BulletKind::Standard,
+      radius: 4.,
+      range: 220.,
+      velocity: 80.,
+    },
+    deceleration: 40.,
+    indirect_fire: false,
+    radius: 30.,
+    reload_time: 3.,
+    speed: 20.,
+    turret_rotation_speed: TAU / 4.,
+    turret_traverse_limit: None,
+  };
+
+  pub const MEDIUM: Self = Self {
+    acceleration: TANK_ACCELERATION_METERS_PER_SECOND_SQUARED,
+    ammo_max: TANK_AMMO_MAX,
+    armor: 1.,
+    body_rotation_speed: TANK_BODY_ROTATION_SPEED_RADIANS_PER_SECOND,
+    bullet: BulletConfig {
+      damage: BULLET_DAMAGE,
+      kind: BulletKind::Standard,
+      radius: BULLET_RADIUS,
+      range: BULLET_RANGE,
+      velocity: BULLET_VELOCITY,
+    },
+    deceleration: TANK_DECELERATION_METERS_PER_SECOND_SQUARED,
+    indirect_fire: false,
+    radius: TANK_RADIUS,
+    reload_time: TANK_RELOAD_TIME_SECONDS,
+    speed: TANK_SPEED_METERS_PER_SECOND,
+    turret_rotation_speed: TANK_TURRET_ROTATION_SPEED_RADIANS_PER_SECOND,
+    turret_traverse_limit: None,
+  };
+
+  pub const SCOUT: Self = Self {
+    acceleration: 45.,
+    ammo_max: 20,
+    armor: 0.75,
+    body_rotation_speed: TAU / 4.,
+    bullet: BulletConfig {
+      damage: 0.5,
+      kind: BulletKind::Standard,
+      radius: 2.,
+      range: 150.,
+      velocity: 120.,
+    },
+    deceleration: 80.,
+    indirect_fire: false,
+    radius: 18.,
+    reload_time: 1.,
+    speed: 45.,
+    turret_rotation_speed: TAU,
+    turret_traverse_limit: None,
+  };
+}
+
+impl Default for TankArchetype {
+  fn default() -> Self {
+    TankArchetype::MEDIUM
+  }
+}
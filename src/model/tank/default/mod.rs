@@ -2,62 +2,94 @@
 //! - Tank state for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023-2024 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-03-29
-//! - Updated: 2024-07-29
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
 use self::state::State;
-use super::{Color, Tank, TankAccessor};
-use crate::ai::tank_operator::TankOperator;
+use super::archetype::TankArchetype;
+use super::weapon::Weapon;
+use super::{Color, Tank, TankAccessor, TankPart, TurretIdleMode};
 use crate::constant::{
-  TANK_AMMO_INITIAL, TANK_AMMO_MAX,
-  TANK_BODY_ROTATION_SPEED_RADIANS_PER_SECOND, TANK_BURNING_DURATION_SECONDS,
-  TANK_DAMAGE_MAX, TANK_RADIUS, TANK_RELOAD_TIME_SECONDS,
-  TANK_SPARKING_DURATION_SECONDS, TANK_SPEED_METERS_PER_SECOND,
-  TANK_TREAD_LENGTH, TANK_TURRET_ROTATION_SPEED_RADIANS_PER_SECOND, TANK_Z,
+  MINE_RADIUS, SHELL_RANGE_MAX, SHELL_RANGE_MIN, TANK_ARMOR_FRONT_MULTIPLIER,
+  TANK_ARMOR_REAR_MULTIPLIER, TANK_ARMOR_SIDE_MULTIPLIER,
+  TANK_BURNING_DURATION_SECONDS, TANK_DAMAGE_MAX,
+  TANK_KNOCKBACK_DECELERATION_METERS_PER_SECOND_SQUARED,
+  TANK_MINE_COUNT_INITIAL, TANK_REVERSE_DISTANCE_MAX,
+  TANK_REVERSE_SPEED_FRACTION, TANK_SPARKING_DURATION_SECONDS,
+  TANK_STUCK_DISTANCE_MIN, TANK_STUCK_WINDOW_SECONDS, TANK_TREAD_LENGTH,
+  TANK_TREAD_REPAIR_TIME_SECONDS, TANK_TURRET_RADIUS_FRACTION,
+  TANK_TURRET_REPAIR_TIME_SECONDS, TANK_TURRET_SWEEP_ANGLE, TANK_Z,
 };
-use crate::model::bullet::Bullet;
-use crate::model::{Damageable, Model, ModelAccessor};
+use crate::model::bullet::{Bullet, BulletConfig, BulletKind};
+use crate::model::mine::Mine;
+use crate::model::obstacle::Obstacle;
+use crate::model::shell::Shell;
+use crate::model::{Damageable, Model, ModelAccessor, Pushable};
 use crate::world::factory::WorldFactory;
 use crate::world::World;
 use com_croftsoft_core::math::geom::circle::{Circle, CircleAccessor};
 use com_croftsoft_core::math::geom::point_2dd::Point2DD;
 use com_croftsoft_core::math::geom::point_xy::PointXY;
 use com_croftsoft_lib_role::PreparerMut;
-use core::f64::consts::{PI, TAU};
-use std::cell::RefCell;
-use std::collections::VecDeque;
-use std::rc::{Rc, Weak};
+use core::f64::consts::{FRAC_PI_4, PI, TAU};
+use std::rc::Weak;
 
 pub mod state;
 
 pub struct DefaultTank {
-  ammo: usize,
+  archetype: TankArchetype,
   body_heading: f64,
   burning_time_remaining: f64,
   circle: Circle,
   color: Color,
   damage: f64,
+  damaged_part: TankPart,
   // TODO: was PointXY
   destination: Option<Point2DD>,
+  // True while lined up on and driving toward the destination, as opposed to
+  // turning in place or immobilized
+  driving: bool,
   dry_firing: bool,
   factory: Weak<dyn WorldFactory>,
   fire_requested: bool,
   firing: bool,
   id: usize,
+  // The velocity from being pushed by an explosion
+  knockback_velocity_x: f64,
+  knockback_velocity_y: f64,
+  mine_count: usize,
+  mine_requested: bool,
+  // Seconds until the gun can fire again, set by the weapon last fired
+  reload_time_remaining: f64,
+  // The point at which to lob a shell, if requested
+  shell_requested: Option<Point2DD>,
   sparking_time_remaining: f64,
   state: State,
-  target_point: Point2DD,
-  time_since_last_fired: f64,
+  stuck: bool,
+  stuck_window_center: Point2DD,
+  stuck_window_time: f64,
+  target_point: Option<Point2DD>,
+  tread_left_repair_time: f64,
   tread_offset_left: f64,
   tread_offset_right: f64,
+  tread_right_repair_time: f64,
   turret_heading: f64,
+  turret_idle_mode: TurretIdleMode,
+  turret_repair_time: f64,
+  // Positive or negative one for the direction of the current sweep
+  turret_sweep_direction: f64,
+  turret_traverse_limit: Option<f64>,
   updated: bool,
+  velocity: f64,
+  weapon_index: usize,
+  // In order of preference when restocking
+  weapons: [Weapon; 3],
   world: Weak<dyn World>,
 }
 
@@ -67,15 +99,31 @@ impl DefaultTank {
     center_x: f64,
     center_y: f64,
   ) {
-    self.ammo = TANK_AMMO_INITIAL;
+    for weapon in self.weapons.iter_mut() {
+      weapon.ammo = weapon.ammo_initial;
+    }
+    self.weapon_index = 0;
     self.damage = 0.;
+    self.damaged_part = TankPart::Hull;
+    self.knockback_velocity_x = 0.;
+    self.knockback_velocity_y = 0.;
+    self.mine_count = TANK_MINE_COUNT_INITIAL;
+    self.mine_requested = false;
+    // Loaded and ready to fire on spawn
+    self.reload_time_remaining = 0.;
+    self.shell_requested = None;
+    self.tread_left_repair_time = 0.;
+    self.tread_right_repair_time = 0.;
+    self.turret_repair_time = 0.;
     self.prepare();
     self.updated = true;
     self.circle.center_x = center_x;
     self.circle.center_y = center_y;
+    self.velocity = 0.;
   }
 
   pub fn new(
+    archetype: TankArchetype,
     center_x: f64,
     center_y: f64,
     color: Color,
@@ -86,37 +134,190 @@ impl DefaultTank {
     let circle: Circle = Circle {
       center_x: 0.,
       center_y: 0.,
-      radius: TANK_RADIUS,
+      radius: archetype.radius,
     };
     let mut tank: DefaultTank = Self {
-      ammo: 0,
+      archetype,
       body_heading: 0.,
       burning_time_remaining: 0.,
       circle,
       color,
       damage: 0.,
+      damaged_part: TankPart::Hull,
       destination: None,
+      driving: false,
       dry_firing: false,
       factory,
       fire_requested: false,
       firing: false,
       id,
+      knockback_velocity_x: 0.,
+      knockback_velocity_y: 0.,
+      mine_count: 0,
+      mine_requested: false,
+      reload_time_remaining: 0.,
+      shell_requested: None,
       sparking_time_remaining: 0.,
       state: State::default(),
-      target_point: Point2DD::default(),
-      time_since_last_fired: 0.,
+      stuck: false,
+      stuck_window_center: Point2DD::default(),
+      stuck_window_time: 0.,
+      target_point: None,
+      tread_left_repair_time: 0.,
       tread_offset_left: 0.,
       tread_offset_right: 0.,
+      tread_right_repair_time: 0.,
       turret_heading: 0.,
+      turret_idle_mode: TurretIdleMode::default(),
+      turret_repair_time: 0.,
+      turret_sweep_direction: 1.,
+      turret_traverse_limit: archetype.turret_traverse_limit,
       updated: false,
+      velocity: 0.,
+      weapon_index: 0,
+      weapons: [
+        Weapon::new(&archetype, BulletKind::Standard),
+        Weapon::new(&archetype, BulletKind::HighExplosive),
+        Weapon::new(&archetype, BulletKind::ArmorPiercing),
+      ],
       world,
     };
     tank.initialize(center_x, center_y);
     tank
   }
 
+  // Adds the damage after armor and disables the damaged part for a while
+  fn apply_damage(
+    &mut self,
+    damage: f64,
+    damaged_part: TankPart,
+  ) {
+    if damage <= 0. {
+      return;
+    }
+    let damage: f64 = damage / self.archetype.armor;
+    match &mut self.state {
+      State::Burning(_) | State::Inactive => return,
+      State::Nominal(state_operator) => {
+        self.damage += damage;
+        if self.damage > TANK_DAMAGE_MAX {
+          self.state = state_operator.to_burning();
+          self.burning_time_remaining = TANK_BURNING_DURATION_SECONDS;
+        } else {
+          self.state = state_operator.to_sparking();
+          self.sparking_time_remaining = TANK_SPARKING_DURATION_SECONDS;
+        }
+      },
+      State::Sparking(state_operator) => {
+        self.damage += damage;
+        if self.damage > TANK_DAMAGE_MAX {
+          self.state = state_operator.to_burning();
+          self.burning_time_remaining = TANK_BURNING_DURATION_SECONDS;
+        } else {
+          self.sparking_time_remaining = TANK_SPARKING_DURATION_SECONDS;
+        }
+      },
+    }
+    self.updated = true;
+    self.damaged_part = damaged_part;
+    match damaged_part {
+      TankPart::Hull => (),
+      TankPart::TreadLeft => {
+        self.tread_left_repair_time = TANK_TREAD_REPAIR_TIME_SECONDS;
+      },
+      TankPart::TreadRight => {
+        self.tread_right_repair_time = TANK_TREAD_REPAIR_TIME_SECONDS;
+      },
+      TankPart::Turret => {
+        self.turret_repair_time = TANK_TURRET_REPAIR_TIME_SECONDS;
+      },
+    }
+  }
+
+  // The armor multiplier and the part damaged by a projectile travelling
+  // along the heading that struck at the point
+  fn locate_hit(
+    &self,
+    heading: f64,
+    point: Point2DD,
+  ) -> (f64, TankPart) {
+    // Direction from the tank toward where the projectile came from
+    let hit_angle: f64 =
+      DefaultTank::compute_heading_delta(self.body_heading, heading + PI);
+    let front: bool = hit_angle.abs() <= FRAC_PI_4;
+    let rear: bool = hit_angle.abs() >= PI - FRAC_PI_4;
+    let armor_multiplier: f64 = if front {
+      TANK_ARMOR_FRONT_MULTIPLIER
+    } else if rear {
+      TANK_ARMOR_REAR_MULTIPLIER
+    } else {
+      TANK_ARMOR_SIDE_MULTIPLIER
+    };
+    // Only a projectile that reaches the turret itself damages the turret
+    let contact_distance: f64 = point.distance_to(&self.get_center());
+    let tank_part: TankPart =
+      if contact_distance < self.circle.radius * TANK_TURRET_RADIUS_FRACTION {
+        TankPart::Turret
+      } else if front || rear {
+        TankPart::Hull
+      } else if hit_angle > 0. {
+        TankPart::TreadRight
+      } else {
+        TankPart::TreadLeft
+      };
+    (armor_multiplier, tank_part)
+  }
+
   // private update functions
 
+  // Moves the value toward the target by no more than the maximum change
+  fn approach(
+    value: f64,
+    target: f64,
+    change_max: f64,
+  ) -> f64 {
+    if (target - value).abs() <= change_max {
+      target
+    } else {
+      value + change_max.copysign(target - value)
+    }
+  }
+
+  // Heading difference normalized to the range from -PI to PI
+  fn compute_heading_delta(
+    from_heading: f64,
+    to_heading: f64,
+  ) -> f64 {
+    (to_heading - from_heading + PI).rem_euclid(TAU) - PI
+  }
+
+  // Backs up to a nearby destination behind the tank when that is faster than
+  // turning around to drive forward
+  fn is_reversing(
+    &self,
+    aim_heading: f64,
+    distance: f64,
+  ) -> bool {
+    if distance > TANK_REVERSE_DISTANCE_MAX {
+      return false;
+    }
+    let forward_time: f64 = self.calculate_drive_time(
+      self.body_heading,
+      aim_heading,
+      self.velocity,
+      distance,
+      false,
+    );
+    let reverse_time: f64 = self.calculate_drive_time(
+      self.body_heading,
+      aim_heading + PI,
+      self.velocity,
+      distance,
+      true,
+    );
+    reverse_time < forward_time
+  }
+
   fn is_turning_right(
     body_heading_new: f64,
     body_heading_old: f64,
@@ -158,24 +359,32 @@ impl DefaultTank {
   // private update methods
 
   fn update_ammo(&mut self) {
-    if self.ammo >= TANK_AMMO_MAX {
-      return;
-    }
-    let mut ammo_needed: usize = TANK_AMMO_MAX - self.ammo;
+    let center_x: f64 = self.circle.center_x;
+    let center_y: f64 = self.circle.center_y;
     let ammo_dumps = self.world.upgrade().unwrap().get_ammo_dumps();
-    for ammo_dump in ammo_dumps.borrow_mut().iter_mut() {
-      if !ammo_dump.contains(self.circle.center_x, self.circle.center_y) {
+    // Restocks the standard weapon first
+    for weapon in self.weapons.iter_mut() {
+      if weapon.ammo >= weapon.ammo_max {
         continue;
       }
-      let dump_ammo = ammo_dump.get_ammo();
-      if ammo_needed as f64 <= dump_ammo {
-        self.ammo = TANK_AMMO_MAX;
-        ammo_dump.set_ammo(dump_ammo - ammo_needed as f64);
-        break;
-      } else {
-        self.ammo += dump_ammo as usize;
-        ammo_dump.set_ammo(dump_ammo - (dump_ammo as usize) as f64);
-        ammo_needed = TANK_AMMO_MAX - self.ammo;
+      let mut ammo_needed: usize = weapon.ammo_max - weapon.ammo;
+      for ammo_dump in ammo_dumps.borrow_mut().iter_mut() {
+        // Only the team that captured the ammo dump can draw from it
+        if ammo_dump.get_owner() != Some(self.color)
+          || !ammo_dump.contains(center_x, center_y)
+        {
+          continue;
+        }
+        let dump_ammo = ammo_dump.get_ammo();
+        if ammo_needed as f64 <= dump_ammo {
+          weapon.ammo = weapon.ammo_max;
+          ammo_dump.set_ammo(dump_ammo - ammo_needed as f64);
+          break;
+        } else {
+          weapon.ammo += dump_ammo as usize;
+          ammo_dump.set_ammo(dump_ammo - (dump_ammo as usize) as f64);
+          ammo_needed = weapon.ammo_max - weapon.ammo;
+        }
       }
     }
   }
@@ -184,7 +393,8 @@ impl DefaultTank {
     &mut self,
     time_delta: f64,
   ) {
-    self.time_since_last_fired += time_delta;
+    self.reload_time_remaining =
+      (self.reload_time_remaining - time_delta).max(0.);
     if !self.fire_requested {
       return;
     }
@@ -193,73 +403,214 @@ impl DefaultTank {
       return;
     }
     self.updated = true;
-    if self.ammo < 1 || self.time_since_last_fired < TANK_RELOAD_TIME_SECONDS {
+    let weapon: &mut Weapon = &mut self.weapons[self.weapon_index];
+    if weapon.ammo < 1 || self.reload_time_remaining > 0. {
       self.dry_firing = true;
       return;
     }
-    self.ammo -= 1;
+    weapon.ammo -= 1;
+    // Every weapon waits on the reload of the one just fired
+    self.reload_time_remaining = weapon.reload_time;
+    let bullet_config: BulletConfig = weapon.bullet;
     self.firing = true;
-    self.time_since_last_fired = 0.;
+    let bullet_distance: f64 = self.circle.radius + bullet_config.radius;
     let bullet_origin_x: f64 =
-      self.circle.center_x + (TANK_RADIUS + 3.) * self.turret_heading.cos();
+      self.circle.center_x + bullet_distance * self.turret_heading.cos();
     let bullet_origin_y: f64 =
-      self.circle.center_y + (TANK_RADIUS + 3.) * self.turret_heading.sin();
+      self.circle.center_y + bullet_distance * self.turret_heading.sin();
     let bullet: Box<dyn Bullet> = self.factory.upgrade().unwrap().make_bullet(
+      bullet_config,
       self.turret_heading,
       bullet_origin_x,
       bullet_origin_y,
+      self.world.clone(),
     );
     self.world.upgrade().unwrap().add_bullet(bullet);
   }
 
+  // Slides the tank with the push from an explosion until it stops or hits
+  // something
+  fn update_knockback(
+    &mut self,
+    time_delta: f64,
+  ) {
+    let speed: f64 = self.knockback_velocity_x.hypot(self.knockback_velocity_y);
+    if speed <= 0. {
+      return;
+    }
+    let old_x = self.circle.center_x;
+    let old_y = self.circle.center_y;
+    self.circle.center_x += time_delta * self.knockback_velocity_x;
+    self.circle.center_y += time_delta * self.knockback_velocity_y;
+    if self
+      .world
+      .upgrade()
+      .unwrap()
+      .is_blocked_by_impassable(&self.circle)
+    {
+      self.circle.center_x = old_x;
+      self.circle.center_y = old_y;
+      self.knockback_velocity_x = 0.;
+      self.knockback_velocity_y = 0.;
+      return;
+    }
+    self.updated = true;
+    let speed_new: f64 = (speed
+      - time_delta * TANK_KNOCKBACK_DECELERATION_METERS_PER_SECOND_SQUARED)
+      .max(0.);
+    self.knockback_velocity_x *= speed_new / speed;
+    self.knockback_velocity_y *= speed_new / speed;
+  }
+
+  fn update_mine(&mut self) {
+    if !self.mine_requested {
+      return;
+    }
+    self.mine_requested = false;
+    if self.mine_count < 1 {
+      return;
+    }
+    self.mine_count -= 1;
+    self.updated = true;
+    let mine_distance: f64 = self.circle.radius + MINE_RADIUS;
+    let factory = self.factory.upgrade().unwrap();
+    let mine: Box<dyn Mine> = factory.make_mine(
+      self.circle.center_x - mine_distance * self.body_heading.cos(),
+      self.circle.center_y - mine_distance * self.body_heading.sin(),
+      self.color,
+      self.factory.clone(),
+      self.world.clone(),
+    );
+    self.world.upgrade().unwrap().add_mine(mine);
+  }
+
+  // Lobs a shell from the standard weapon along the turret heading to the
+  // distance of the requested point, clamped to the range of the shells
+  fn update_shell(&mut self) {
+    let Some(target_point) = self.shell_requested.take() else {
+      return;
+    };
+    if !self.archetype.indirect_fire || self.firing || self.dry_firing {
+      return;
+    }
+    self.updated = true;
+    let weapon: &mut Weapon = &mut self.weapons[0];
+    if weapon.ammo < 1 || self.reload_time_remaining > 0. {
+      self.dry_firing = true;
+      return;
+    }
+    weapon.ammo -= 1;
+    self.reload_time_remaining = weapon.reload_time;
+    self.firing = true;
+    let origin: Point2DD = self.get_center();
+    let distance: f64 = origin
+      .distance_to(&target_point)
+      .clamp(SHELL_RANGE_MIN, SHELL_RANGE_MAX);
+    let target = Point2DD::new(
+      origin.x + distance * self.turret_heading.cos(),
+      origin.y + distance * self.turret_heading.sin(),
+    );
+    let factory = self.factory.upgrade().unwrap();
+    let shell: Box<dyn Shell> = factory.make_shell(
+      self.factory.clone(),
+      origin,
+      target,
+      self.world.clone(),
+    );
+    self.world.upgrade().unwrap().add_shell(shell);
+  }
+
   fn update_position(
     &mut self,
     time_delta: f64,
   ) {
-    if self.destination.is_none() {
+    self.driving = false;
+    let Some(destination) = self.destination else {
+      return;
+    };
+    if self.is_part_disabled(TankPart::TreadLeft)
+      || self.is_part_disabled(TankPart::TreadRight)
+    {
+      // Immobilized until the tread is repaired
+      self.velocity = 0.;
       return;
     }
-    // TODO: something better than unwrap
-    let delta_x: f64 = self.destination.unwrap().x - self.circle.center_x;
-    let delta_y: f64 = self.destination.unwrap().y - self.circle.center_y;
+    let delta_x: f64 = destination.x - self.circle.center_x;
+    let delta_y: f64 = destination.y - self.circle.center_y;
+    let distance: f64 = delta_x.hypot(delta_y);
     // TODO: the following was remarked out in the old code
     // if ((Math.abs(deltaX) < 0.5) && (Math.abs(deltaY) < 0.5)) {
     //   return;
     // }
     let mut aim_heading: f64 = delta_y.atan2(delta_x);
-    if aim_heading < 0. {
-      aim_heading += TAU;
+    let reversing: bool = self.is_reversing(aim_heading, distance);
+    if reversing {
+      // Point the rear of the tank toward the destination
+      aim_heading += PI;
     }
+    aim_heading = aim_heading.rem_euclid(TAU);
     let body_heading_old: f64 = self.body_heading;
     let body_heading_new: f64 = DefaultTank::rotate_toward_heading(
       self.body_heading,
       aim_heading,
-      time_delta * TANK_BODY_ROTATION_SPEED_RADIANS_PER_SECOND,
+      time_delta * self.archetype.body_rotation_speed,
     );
     if body_heading_new != self.body_heading {
       self.updated = true;
       self.body_heading = body_heading_new;
+      // The body carries the turret around with it
+      self.turret_heading = (self.turret_heading + body_heading_new
+        - body_heading_old)
+        .rem_euclid(TAU);
     }
     let center_old = self.circle.get_center_point_2dd();
     if self.body_heading != aim_heading {
-      let center_new = self.circle.get_center_point_2dd();
-      self.update_tread_offsets(
-        body_heading_new,
-        body_heading_old,
-        center_new,
-        center_old,
-        time_delta,
+      // Coast along the body heading while braking to turn
+      self.velocity = DefaultTank::approach(
+        self.velocity,
+        0.,
+        time_delta * self.archetype.deceleration,
+      );
+    } else {
+      self.driving = true;
+      let terrain_speed_multiplier: f64 = self
+        .world
+        .upgrade()
+        .unwrap()
+        .get_terrain()
+        .borrow()
+        .get_speed_multiplier(&center_old);
+      let top_speed: f64 = terrain_speed_multiplier
+        * if reversing {
+          self.get_tank_reverse_speed()
+        } else {
+          self.archetype.speed
+        };
+      // Slow down in time to stop at the destination
+      let target_speed: f64 =
+        top_speed.min((2. * self.archetype.deceleration * distance).sqrt());
+      let target_velocity: f64 = if reversing {
+        -target_speed
+      } else {
+        target_speed
+      };
+      let braking: bool = self.velocity * target_velocity < 0.
+        || target_velocity.abs() < self.velocity.abs();
+      let acceleration: f64 = if braking {
+        self.archetype.deceleration
+      } else {
+        self.archetype.acceleration
+      };
+      self.velocity = DefaultTank::approach(
+        self.velocity,
+        target_velocity,
+        time_delta * acceleration,
       );
-      return;
     }
-    let mut move_x: f64 =
-      time_delta * TANK_SPEED_METERS_PER_SECOND * self.body_heading.cos();
-    let mut move_y: f64 =
-      time_delta * TANK_SPEED_METERS_PER_SECOND * self.body_heading.sin();
-    if move_x.abs() > delta_x.abs() {
+    let mut move_x: f64 = time_delta * self.velocity * self.body_heading.cos();
+    let mut move_y: f64 = time_delta * self.velocity * self.body_heading.sin();
+    if move_x.hypot(move_y) > distance {
       move_x = delta_x;
-    }
-    if move_y.abs() > delta_y.abs() {
       move_y = delta_y;
     }
     let old_x = self.circle.center_x;
@@ -277,6 +628,7 @@ impl DefaultTank {
     {
       self.circle.center_x = old_x;
       self.circle.center_y = old_y;
+      self.velocity = 0.;
       if self
         .world
         .upgrade()
@@ -301,6 +653,50 @@ impl DefaultTank {
     );
   }
 
+  fn update_repairs(
+    &mut self,
+    time_delta: f64,
+  ) {
+    self.tread_left_repair_time =
+      (self.tread_left_repair_time - time_delta).max(0.);
+    self.tread_right_repair_time =
+      (self.tread_right_repair_time - time_delta).max(0.);
+    self.turret_repair_time = (self.turret_repair_time - time_delta).max(0.);
+  }
+
+  // Detects when the tank fails to move toward its destination over a window
+  // of time spent driving
+  fn update_stuck(
+    &mut self,
+    time_delta: f64,
+  ) {
+    let center: Point2DD = self.circle.get_center_point_2dd();
+    let Some(destination) = self.destination else {
+      self.stuck_window_time = 0.;
+      return;
+    };
+    if center.distance_to(&destination) < TANK_STUCK_DISTANCE_MIN {
+      self.stuck_window_time = 0.;
+      return;
+    }
+    // Turning in place or waiting on a tread repair is not being stuck
+    if !self.driving {
+      return;
+    }
+    if self.stuck_window_time == 0. {
+      self.stuck_window_center = center;
+    }
+    self.stuck_window_time += time_delta;
+    if self.stuck_window_time < TANK_STUCK_WINDOW_SECONDS {
+      return;
+    }
+    self.stuck_window_time = 0.;
+    if center.distance_to(&self.stuck_window_center) < TANK_STUCK_DISTANCE_MIN {
+      self.stuck = true;
+      self.updated = true;
+    }
+  }
+
   fn update_tread_offsets(
     &mut self,
     body_heading_new: f64,
@@ -311,20 +707,21 @@ impl DefaultTank {
   ) {
     let mut tread_direction_left = 1.;
     let mut tread_direction_right = 1.;
+    let mut tread_speed: f64 = self.archetype.speed;
     if (body_heading_new - body_heading_old).abs() < TAU / 1000. {
-      if center_new.distance_xy(&center_old) < 0.25 {
+      if center_new.distance_xy(&center_old) <= 0. {
         return;
       }
+      // The treads roll with the velocity of the tank
+      tread_speed = self.velocity;
     } else if DefaultTank::is_turning_right(body_heading_new, body_heading_old)
     {
       tread_direction_right = -1.;
     } else {
       tread_direction_left = -1.;
     }
-    let tread_delta_left =
-      time_delta * TANK_SPEED_METERS_PER_SECOND * tread_direction_left;
-    let tread_delta_right =
-      time_delta * TANK_SPEED_METERS_PER_SECOND * tread_direction_right;
+    let tread_delta_left = time_delta * tread_speed * tread_direction_left;
+    let tread_delta_right = time_delta * tread_speed * tread_direction_right;
     self.tread_offset_left =
       (self.tread_offset_left + tread_delta_left) % TANK_TREAD_LENGTH;
     if self.tread_offset_left < 0. {
@@ -338,23 +735,68 @@ impl DefaultTank {
     self.updated = true;
   }
 
+  // Rotates the turret toward the target or per the idle mode.  The turret
+  // stays stabilized on its target by compensating for the body rotation that
+  // carries it around.
   fn update_turret_heading(
     &mut self,
     time_delta: f64,
   ) {
-    let center_x: f64 = self.circle.center_x;
-    let center_y: f64 = self.circle.center_y;
-    // TODO: check if atan2 arguments reversed
-    let mut desired_turret_heading: f64 =
-      (self.target_point.y - center_y).atan2(self.target_point.x - center_x);
-    if desired_turret_heading < 0. {
-      desired_turret_heading += TAU;
+    if self.is_part_disabled(TankPart::Turret) {
+      return;
     }
-    let new_turret_heading = DefaultTank::rotate_toward_heading(
-      self.turret_heading,
-      desired_turret_heading,
-      time_delta * TANK_TURRET_ROTATION_SPEED_RADIANS_PER_SECOND,
-    );
+    let rotation_max: f64 = time_delta * self.archetype.turret_rotation_speed;
+    let desired_turret_heading: f64 = match self.target_point {
+      Some(target_point) => (target_point.y - self.circle.center_y)
+        .atan2(target_point.x - self.circle.center_x),
+      None => match self.turret_idle_mode {
+        TurretIdleMode::Forward => self.body_heading,
+        TurretIdleMode::Hold => self.turret_heading,
+        TurretIdleMode::Sweep => {
+          let relative_heading: f64 = DefaultTank::compute_heading_delta(
+            self.body_heading,
+            self.turret_heading,
+          );
+          let sweep_angle: f64 = self.turret_traverse_limit.map_or(
+            TANK_TURRET_SWEEP_ANGLE,
+            |turret_traverse_limit| {
+              turret_traverse_limit.min(TANK_TURRET_SWEEP_ANGLE)
+            },
+          );
+          let sweep_heading: f64 = self.turret_sweep_direction * sweep_angle;
+          if (sweep_heading - relative_heading).abs() <= rotation_max {
+            self.turret_sweep_direction = -self.turret_sweep_direction;
+          }
+          self.body_heading + self.turret_sweep_direction * sweep_angle
+        },
+      },
+    };
+    let new_turret_heading: f64 =
+      if let Some(turret_traverse_limit) = self.turret_traverse_limit {
+        // Traverse within the limits instead of across the rear of the body
+        let relative_heading: f64 = DefaultTank::compute_heading_delta(
+          self.body_heading,
+          self.turret_heading,
+        );
+        let desired_relative_heading: f64 = DefaultTank::compute_heading_delta(
+          self.body_heading,
+          desired_turret_heading,
+        )
+        .clamp(-turret_traverse_limit, turret_traverse_limit);
+        (self.body_heading
+          + DefaultTank::approach(
+            relative_heading,
+            desired_relative_heading,
+            rotation_max,
+          ))
+        .rem_euclid(TAU)
+      } else {
+        DefaultTank::rotate_toward_heading(
+          self.turret_heading,
+          desired_turret_heading.rem_euclid(TAU),
+          rotation_max,
+        )
+      };
     if new_turret_heading == self.turret_heading {
       return;
     }
@@ -368,33 +810,7 @@ impl Damageable for DefaultTank {
     &mut self,
     new_damage: f64,
   ) {
-    if new_damage <= 0. {
-      return;
-    }
-    match &mut self.state {
-      State::Burning(_) | State::Inactive => (),
-      State::Nominal(state_operator) => {
-        self.updated = true;
-        self.damage += new_damage;
-        if self.damage > TANK_DAMAGE_MAX {
-          self.state = state_operator.to_burning();
-          self.burning_time_remaining = TANK_BURNING_DURATION_SECONDS;
-        } else {
-          self.state = state_operator.to_sparking();
-          self.sparking_time_remaining = TANK_SPARKING_DURATION_SECONDS;
-        }
-      },
-      State::Sparking(state_operator) => {
-        self.updated = true;
-        self.damage += new_damage;
-        if self.damage > TANK_DAMAGE_MAX {
-          self.state = state_operator.to_burning();
-          self.burning_time_remaining = TANK_BURNING_DURATION_SECONDS;
-        } else {
-          self.sparking_time_remaining = TANK_SPARKING_DURATION_SECONDS;
-        }
-      },
-    }
+    self.apply_damage(new_damage, TankPart::Hull);
   }
 }
 
@@ -403,19 +819,34 @@ impl Model for DefaultTank {
     &mut self,
     time_delta: f64,
   ) {
+    self.stuck = false;
+    self.update_repairs(time_delta);
+    if self.is_active() {
+      self.update_knockback(time_delta);
+    }
     match &mut self.state {
       State::Burning(state_operator) => {
         self.burning_time_remaining -= time_delta;
         if self.burning_time_remaining <= 0. {
           self.state = state_operator.to_inactive();
+          self.updated = true;
+          let wreck: Box<dyn Obstacle> = self
+            .factory
+            .upgrade()
+            .unwrap()
+            .make_wreck(self.circle, self.factory.clone(), self.world.clone());
+          self.world.upgrade().unwrap().add_obstacle(wreck);
         }
       },
       State::Inactive => (),
       State::Nominal(_) => {
         self.update_ammo();
         self.update_position(time_delta);
+        self.update_stuck(time_delta);
         self.update_turret_heading(time_delta);
         self.update_fire(time_delta);
+        self.update_shell();
+        self.update_mine();
       },
       State::Sparking(state_operator) => {
         self.sparking_time_remaining -= time_delta;
@@ -472,7 +903,31 @@ impl PreparerMut for DefaultTank {
   }
 }
 
+impl Pushable for DefaultTank {
+  fn add_impulse(
+    &mut self,
+    impulse_x: f64,
+    impulse_y: f64,
+  ) {
+    if !self.is_active() {
+      return;
+    }
+    self.knockback_velocity_x += impulse_x;
+    self.knockback_velocity_y += impulse_y;
+  }
+}
+
 impl Tank for DefaultTank {
+  fn add_hit(
+    &mut self,
+    damage: f64,
+    heading: f64,
+    point: Point2DD,
+  ) {
+    let (armor_multiplier, tank_part) = self.locate_hit(heading, point);
+    self.apply_damage(armor_multiplier * damage, tank_part);
+  }
+
   // moved from TankConsole
   fn fire(&mut self) {
     self.fire_requested = true;
@@ -485,21 +940,42 @@ impl Tank for DefaultTank {
     self.destination = Some(Point2DD::new(destination.x, destination.y));
   }
 
+  fn lay_mine(&mut self) {
+    self.mine_requested = true;
+  }
+
+  fn lob_shell(
+    &mut self,
+    target_point: &Point2DD,
+  ) {
+    self.shell_requested = Some(*target_point);
+  }
+
   fn rotate_turret(
     &mut self,
     target_point: &Option<Point2DD>,
   ) {
-    if let Some(target_point) = target_point {
-      self.target_point.set_xy_point(target_point);
+    self.target_point = *target_point;
+  }
+
+  fn select_weapon(
+    &mut self,
+    bullet_kind: BulletKind,
+  ) {
+    if let Some(weapon_index) = self
+      .weapons
+      .iter()
+      .position(|weapon| weapon.bullet.kind == bullet_kind)
+    {
+      self.weapon_index = weapon_index;
     }
-    // TODO: else if None, rotate turret forward maybe
   }
 
   fn set_ammo(
     &mut self,
     ammo: usize,
   ) {
-    self.ammo = ammo;
+    self.weapons[self.weapon_index].ammo = ammo;
   }
 
   fn set_body_heading(
@@ -515,11 +991,73 @@ impl Tank for DefaultTank {
   ) {
     self.turret_heading = turret_heading;
   }
+
+  fn set_turret_idle_mode(
+    &mut self,
+    turret_idle_mode: TurretIdleMode,
+  ) {
+    self.turret_idle_mode = turret_idle_mode;
+  }
+
+  fn set_turret_traverse_limit(
+    &mut self,
+    turret_traverse_limit: Option<f64>,
+  ) {
+    self.turret_traverse_limit = turret_traverse_limit;
+  }
 }
 
 impl TankAccessor for DefaultTank {
+  fn calculate_drive_time(
+    &self,
+    from_heading: f64,
+    to_heading: f64,
+    velocity: f64,
+    distance: f64,
+    reversing: bool,
+  ) -> f64 {
+    let rotation_time: f64 =
+      DefaultTank::compute_heading_delta(from_heading, to_heading).abs()
+        / self.archetype.body_rotation_speed;
+    let top_speed: f64 = if reversing {
+      self.get_tank_reverse_speed()
+    } else {
+      self.archetype.speed
+    };
+    // The tank coasts while braking to turn and stops to change direction
+    let coasting_time: f64 =
+      rotation_time.min(velocity.abs() / self.archetype.deceleration);
+    let coasting_distance: f64 = velocity.abs() * coasting_time
+      - 0.5 * self.archetype.deceleration * coasting_time * coasting_time;
+    let mut entry_speed: f64 = (velocity.abs()
+      - self.archetype.deceleration * rotation_time)
+      .max(0.)
+      .min(top_speed);
+    let mut distance: f64 = distance;
+    if (velocity < 0.) != reversing {
+      entry_speed = 0.;
+    } else {
+      distance = (distance - coasting_distance).max(0.);
+    }
+    let acceleration: f64 = self.archetype.acceleration;
+    let acceleration_distance: f64 =
+      (top_speed * top_speed - entry_speed * entry_speed) / (2. * acceleration);
+    if distance <= acceleration_distance {
+      let exit_speed: f64 =
+        (entry_speed * entry_speed + 2. * acceleration * distance).sqrt();
+      return rotation_time + (exit_speed - entry_speed) / acceleration;
+    }
+    rotation_time
+      + (top_speed - entry_speed) / acceleration
+      + (distance - acceleration_distance) / top_speed
+  }
+
   fn get_ammo(&self) -> usize {
-    self.ammo
+    self.weapons.iter().map(|weapon| weapon.ammo).sum()
+  }
+
+  fn get_archetype(&self) -> TankArchetype {
+    self.archetype
   }
 
   fn get_body_heading(&self) -> f64 {
@@ -527,24 +1065,38 @@ impl TankAccessor for DefaultTank {
   }
 
   fn get_body_rotation_speed(&self) -> f64 {
-    TANK_BODY_ROTATION_SPEED_RADIANS_PER_SECOND
+    self.archetype.body_rotation_speed
   }
 
   fn get_center(&self) -> Point2DD {
     Point2DD::new(self.circle.center_x, self.circle.center_y)
   }
 
+  // Prefers an ammo dump owned by the team, then an unowned one, then one to
+  // capture from another team, skipping any that are contested
   fn get_closest_ammo_dump_circle(&self) -> Option<Circle> {
     let mut closest_ammo_dump_circle: Option<Circle> = None;
     let tank_center = self.get_center();
+    let mut closest_rank: usize = usize::MAX;
     let mut closest_distance: f64 = f64::INFINITY;
     let world = &self.world.upgrade().unwrap();
     let ammo_dumps = world.get_ammo_dumps();
     for ammo_dump in ammo_dumps.borrow().iter() {
+      if ammo_dump.is_contested() {
+        continue;
+      }
+      let rank: usize = match ammo_dump.get_owner() {
+        Some(owner) if owner == self.color => 0,
+        None => 1,
+        Some(_) => 2,
+      };
       let ammo_dump_circle = ammo_dump.get_circle();
       let ammo_dump_center = ammo_dump_circle.get_center_point_2dd();
       let distance: f64 = tank_center.distance_to(&ammo_dump_center);
-      if distance < closest_distance {
+      if rank < closest_rank
+        || (rank == closest_rank && distance < closest_distance)
+      {
+        closest_rank = rank;
         closest_distance = distance;
         closest_ammo_dump_circle = Some(ammo_dump_circle);
       }
@@ -552,39 +1104,6 @@ impl TankAccessor for DefaultTank {
     closest_ammo_dump_circle
   }
 
-  fn get_closest_enemy_tank_circle(
-    &self,
-    tank_operators: Rc<RefCell<VecDeque<Box<dyn TankOperator>>>>,
-  ) -> Option<Circle> {
-    let mut closest_distance: f64 = f64::INFINITY;
-    let mut found = false;
-    let tank_operators = tank_operators.borrow();
-    let length = tank_operators.len();
-    let tank_center = Point2DD::new(self.circle.center_x, self.circle.center_y);
-    let mut closest_enemy_tank_circle = Circle::default();
-    for i in 0..length {
-      let tank = tank_operators[i].get_tank();
-      let tank = tank.borrow();
-      if tank.is_burning()
-        || !tank.is_active()
-        || tank.get_color() == self.color
-      {
-        continue;
-      }
-      let enemy_tank_center = tank.get_center();
-      let distance = tank_center.distance_to(&enemy_tank_center);
-      if distance < closest_distance {
-        found = true;
-        closest_distance = distance;
-        closest_enemy_tank_circle = tank.get_circle();
-      }
-    }
-    if found {
-      return Some(closest_enemy_tank_circle);
-    }
-    None
-  }
-
   fn get_color(&self) -> Color {
     self.color
   }
@@ -593,12 +1112,24 @@ impl TankAccessor for DefaultTank {
     self.damage
   }
 
+  fn get_damaged_part(&self) -> TankPart {
+    self.damaged_part
+  }
+
+  fn get_mine_count(&self) -> usize {
+    self.mine_count
+  }
+
   fn get_radius(&self) -> f64 {
     self.circle.radius
   }
 
+  fn get_tank_reverse_speed(&self) -> f64 {
+    self.archetype.speed * TANK_REVERSE_SPEED_FRACTION
+  }
+
   fn get_tank_speed(&self) -> f64 {
-    TANK_SPEED_METERS_PER_SECOND
+    self.archetype.speed
   }
 
   fn get_tread_offset_left(&self) -> f64 {
@@ -613,6 +1144,21 @@ impl TankAccessor for DefaultTank {
     self.turret_heading
   }
 
+  fn get_velocity(&self) -> f64 {
+    self.velocity
+  }
+
+  fn get_weapon(
+    &self,
+    bullet_kind: BulletKind,
+  ) -> Option<Weapon> {
+    self
+      .weapons
+      .iter()
+      .find(|weapon| weapon.bullet.kind == bullet_kind)
+      .copied()
+  }
+
   fn is_burning(&self) -> bool {
     matches!(self.state, State::Burning(_))
   }
@@ -625,7 +1171,27 @@ impl TankAccessor for DefaultTank {
     self.firing
   }
 
+  fn is_part_disabled(
+    &self,
+    tank_part: TankPart,
+  ) -> bool {
+    match tank_part {
+      TankPart::Hull => false,
+      TankPart::TreadLeft => self.tread_left_repair_time > 0.,
+      TankPart::TreadRight => self.tread_right_repair_time > 0.,
+      TankPart::Turret => self.turret_repair_time > 0.,
+    }
+  }
+
+  fn is_reloading(&self) -> bool {
+    self.reload_time_remaining > 0.
+  }
+
   fn is_sparking(&self) -> bool {
     matches!(self.state, State::Sparking(_))
   }
+
+  fn is_stuck(&self) -> bool {
+    self.stuck
+  }
 }
@@ -2,37 +2,70 @@
 //! - Tank traits for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-03-29
-//! - Updated: 2023-08-02
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
-use super::{Damageable, Model, ModelAccessor};
-use crate::ai::tank_operator::TankOperator;
+use self::archetype::TankArchetype;
+use self::weapon::Weapon;
+use super::bullet::BulletKind;
+use super::{Damageable, Model, ModelAccessor, Pushable};
 use com_croftsoft_core::math::geom::circle::Circle;
 use com_croftsoft_core::math::geom::point_2dd::Point2DD;
-use core::cell::RefCell;
-use std::collections::VecDeque;
-use std::rc::Rc;
 
+pub mod archetype;
 pub mod default;
+pub mod weapon;
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum Color {
   // TODO: Support more than two colors
   BLUE,
   RED,
 }
 
+// The part of a tank that a hit damages
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TankPart {
+  #[default]
+  Hull,
+  TreadLeft,
+  TreadRight,
+  Turret,
+}
+
+// What the turret does when there is no target
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TurretIdleMode {
+  // Return to the body heading
+  #[default]
+  Forward,
+  // Hold the current heading
+  Hold,
+  // Sweep back and forth across the body heading
+  Sweep,
+}
+
 // trait TankConsole
-pub trait Tank: Damageable + Model + TankAccessor {
+pub trait Tank: Damageable + Model + Pushable + TankAccessor {
   // TODO: remove this mutator methods by moving TankOperator into Tank update
   //   or by having TankOperator send commands via an input queue
 
+  // Damage from a projectile travelling along the heading that struck at the
+  // point.  The armor that absorbs the hit and the part damaged depend on the
+  // side of the tank facing the projectile.
+  fn add_hit(
+    &mut self,
+    damage: f64,
+    heading: f64,
+    point: Point2DD,
+  );
+
   fn fire(&mut self);
 
   fn go(
@@ -40,11 +73,27 @@ pub trait Tank: Damageable + Model + TankAccessor {
     destination: &Point2DD,
   );
 
+  // Lays a mine behind the tank
+  fn lay_mine(&mut self);
+
+  // Lobs a shell over any obstacles toward the point if the archetype has
+  // indirect fire.  The shell lands along the turret heading.
+  fn lob_shell(
+    &mut self,
+    target_point: &Point2DD,
+  );
+
   fn rotate_turret(
     &mut self,
     target_point: &Option<Point2DD>,
   );
 
+  fn select_weapon(
+    &mut self,
+    bullet_kind: BulletKind,
+  );
+
+  // Sets the ammo of the selected weapon
   fn set_ammo(
     &mut self,
     ammo: usize,
@@ -59,30 +108,66 @@ pub trait Tank: Damageable + Model + TankAccessor {
     &mut self,
     turret_heading: f64,
   );
+
+  fn set_turret_idle_mode(
+    &mut self,
+    turret_idle_mode: TurretIdleMode,
+  );
+
+  // Maximum turret rotation to either side of the body heading, if any
+  fn set_turret_traverse_limit(
+    &mut self,
+    turret_traverse_limit: Option<f64>,
+  );
 }
 
 pub trait TankAccessor: ModelAccessor {
+  // Time to turn in place from one heading to another and then drive the
+  // distance, starting at the velocity along the first heading
+  fn calculate_drive_time(
+    &self,
+    from_heading: f64,
+    to_heading: f64,
+    velocity: f64,
+    distance: f64,
+    reversing: bool,
+  ) -> f64;
+  // Total ammo of all weapons
   fn get_ammo(&self) -> usize;
+  fn get_archetype(&self) -> TankArchetype;
   fn get_body_heading(&self) -> f64;
   fn get_body_rotation_speed(&self) -> f64;
   fn get_center(&self) -> Point2DD;
   // TODO: Move this out of TankAccessor to World or Cartographer
   // TODO: was PointXY
   fn get_closest_ammo_dump_circle(&self) -> Option<Circle>;
-  // TODO: Move this out of TankAccessor to World or Cartographer
-  fn get_closest_enemy_tank_circle(
-    &self,
-    tank_operators: Rc<RefCell<VecDeque<Box<dyn TankOperator>>>>,
-  ) -> Option<Circle>;
   fn get_color(&self) -> Color;
   fn get_damage(&self) -> f64;
+  // The part damaged by the most recent hit
+  fn get_damaged_part(&self) -> TankPart;
+  fn get_mine_count(&self) -> usize;
   fn get_radius(&self) -> f64;
+  fn get_tank_reverse_speed(&self) -> f64;
   fn get_tank_speed(&self) -> f64;
   fn get_tread_offset_left(&self) -> f64;
   fn get_tread_offset_right(&self) -> f64;
   fn get_turret_heading(&self) -> f64;
+  // Signed speed along the body heading where negative is backing up
+  fn get_velocity(&self) -> f64;
+  fn get_weapon(
+    &self,
+    bullet_kind: BulletKind,
+  ) -> Option<Weapon>;
   fn is_burning(&self) -> bool;
   fn is_dry_firing(&self) -> bool;
   fn is_firing(&self) -> bool;
+  // True while a damaged tread or turret is out of action
+  fn is_part_disabled(
+    &self,
+    tank_part: TankPart,
+  ) -> bool;
+  fn is_reloading(&self) -> bool;
   fn is_sparking(&self) -> bool;
+  // True for one update cycle after the tank is detected to be stuck
+  fn is_stuck(&self) -> bool;
 }
@@ -0,0 +1,74 @@
+// =============================================================================
+//! - Weapon for CroftSoft Mars
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use super::archetype::TankArchetype;
+use crate::constant::{
+  TANK_AMMO_INITIAL, WEAPON_ARMOR_PIERCING_AMMO_FRACTION,
+  WEAPON_ARMOR_PIERCING_DAMAGE_FACTOR, WEAPON_ARMOR_PIERCING_RELOAD_FACTOR,
+  WEAPON_HIGH_EXPLOSIVE_AMMO_FRACTION, WEAPON_HIGH_EXPLOSIVE_RELOAD_FACTOR,
+  WEAPON_HIGH_EXPLOSIVE_VELOCITY_FACTOR, WEAPON_SPECIAL_AMMO_INITIAL,
+};
+use crate::model::bullet::{BulletConfig, BulletKind};
+
+// A gun that fires one kind of bullet from its own ammo supply
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Weapon {
+  pub ammo: usize,
+  pub ammo_initial: usize,
+  pub ammo_max: usize,
+  pub bullet: BulletConfig,
+  pub reload_time: f64,
+}
+
+impl Weapon {
+  // The weapon for the kind of bullet scaled from the standard weapon of the
+  // archetype
+  pub fn new(
+    archetype: &TankArchetype,
+    bullet_kind: BulletKind,
+  ) -> Self {
+    let mut bullet: BulletConfig = archetype.bullet;
+    bullet.kind = bullet_kind;
+    let (ammo_fraction, reload_factor): (f64, f64) = match bullet_kind {
+      BulletKind::ArmorPiercing => {
+        bullet.damage *= WEAPON_ARMOR_PIERCING_DAMAGE_FACTOR;
+        (
+          WEAPON_ARMOR_PIERCING_AMMO_FRACTION,
+          WEAPON_ARMOR_PIERCING_RELOAD_FACTOR,
+        )
+      },
+      BulletKind::HighExplosive => {
+        bullet.velocity *= WEAPON_HIGH_EXPLOSIVE_VELOCITY_FACTOR;
+        (
+          WEAPON_HIGH_EXPLOSIVE_AMMO_FRACTION,
+          WEAPON_HIGH_EXPLOSIVE_RELOAD_FACTOR,
+        )
+      },
+      BulletKind::Standard => (1., 1.),
+    };
+    let ammo_max: usize =
+      ((ammo_fraction * archetype.ammo_max as f64) as usize).max(1);
+    let ammo_initial: usize = match bullet_kind {
+      BulletKind::Standard => TANK_AMMO_INITIAL,
+      _ => WEAPON_SPECIAL_AMMO_INITIAL,
+    }
+    .min(ammo_max);
+    Self {
+      ammo: ammo_initial,
+      ammo_initial,
+      ammo_max,
+      bullet,
+      reload_time: reload_factor * archetype.reload_time,
+    }
+  }
+}
@@ -2,10 +2,10 @@
 //! - Default Options structure for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-07-03
-//! - Updated: 2023-07-04
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -16,6 +16,7 @@ use core::cell::Cell;
 
 #[derive(Default)]
 pub struct DefaultOptions {
+  fog_display: Cell<bool>,
   node_display: Cell<bool>,
   path_display: Cell<bool>,
   pause: Cell<bool>,
@@ -23,6 +24,10 @@ pub struct DefaultOptions {
 }
 
 impl Options for DefaultOptions {
+  fn get_fog_display(&self) -> bool {
+    self.fog_display.get()
+  }
+
   fn get_node_display(&self) -> bool {
     self.node_display.get()
   }
@@ -41,6 +46,13 @@ impl Options for DefaultOptions {
 }
 
 impl OptionsMutator for DefaultOptions {
+  fn set_fog_display(
+    &self,
+    fog_display: bool,
+  ) {
+    self.fog_display.set(fog_display);
+  }
+
   fn set_node_display(
     &self,
     node_display: bool,
@@ -2,10 +2,10 @@
 //! - Options trait for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-07-03
-//! - Updated: 2023-07-04
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -14,6 +14,8 @@
 pub mod default;
 
 pub trait Options {
+  fn get_fog_display(&self) -> bool;
+
   fn get_node_display(&self) -> bool;
 
   fn get_path_display(&self) -> bool;
@@ -24,6 +26,11 @@ pub trait Options {
 }
 
 pub trait OptionsMutator {
+  fn set_fog_display(
+    &self,
+    fog_display: bool,
+  );
+
   fn set_node_display(
     &self,
     node_display: bool,
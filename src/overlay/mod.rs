@@ -2,10 +2,10 @@
 //! - Overlay state for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-03-13
-//! - Updated: 2023-06-03
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -13,5 +13,7 @@
 
 #[derive(Default)]
 pub struct Overlay {
+  pub tank_stuck_count: usize,
+  pub tank_stuck_string: String,
   pub update_rate_string: String,
 }
@@ -2,21 +2,25 @@
 //! - Ammo Dump Painter for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-04-30
-//! - Updated: 2023-09-04
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
-use crate::constant::{AMMO_DUMP_FILL_STYLE, AMMO_DUMP_STROKE_STYLE};
+use crate::constant::{
+  AMMO_DUMP_FILL_STYLE, AMMO_DUMP_STROKE_STYLE, TANK_FILL_STYLE_BLUE,
+  TANK_FILL_STYLE_RED,
+};
 use crate::model::ammo_dump::AmmoDump;
+use crate::model::tank::Color;
 use com_croftsoft_core::math::geom::circle::Circle;
 use com_croftsoft_lib_role::Painter;
 use core::cell::RefCell;
-use core::f64::consts::TAU;
+use core::f64::consts::{FRAC_PI_2, TAU};
 use std::collections::VecDeque;
 use std::rc::Rc;
 use wasm_bindgen::JsValue;
@@ -26,20 +30,36 @@ pub struct AmmoDumpPainter {
   ammo_dumps: Rc<RefCell<VecDeque<Box<dyn AmmoDump>>>>,
   context: Rc<RefCell<CanvasRenderingContext2d>>,
   fill_style: JsValue,
+  fill_style_blue: JsValue,
+  fill_style_red: JsValue,
   stroke_style: JsValue,
 }
 
 impl AmmoDumpPainter {
+  fn get_team_style(
+    &self,
+    color: Color,
+  ) -> &JsValue {
+    match color {
+      Color::BLUE => &self.fill_style_blue,
+      Color::RED => &self.fill_style_red,
+    }
+  }
+
   pub fn new(
     context: Rc<RefCell<CanvasRenderingContext2d>>,
     ammo_dumps: Rc<RefCell<VecDeque<Box<dyn AmmoDump>>>>,
   ) -> Self {
     let fill_style: JsValue = JsValue::from_str(AMMO_DUMP_FILL_STYLE);
+    let fill_style_blue: JsValue = JsValue::from_str(TANK_FILL_STYLE_BLUE);
+    let fill_style_red: JsValue = JsValue::from_str(TANK_FILL_STYLE_RED);
     let stroke_style: JsValue = JsValue::from_str(AMMO_DUMP_STROKE_STYLE);
     Self {
       ammo_dumps,
       context,
       fill_style,
+      fill_style_blue,
+      fill_style_red,
       stroke_style,
     }
   }
@@ -48,16 +68,36 @@ impl AmmoDumpPainter {
 impl Painter for AmmoDumpPainter {
   fn paint(&self) {
     let context = self.context.borrow();
-    context.set_fill_style(&self.fill_style);
-    context.set_stroke_style(&self.stroke_style);
     let ammo_dumps = self.ammo_dumps.borrow();
     ammo_dumps.iter().for_each(|ammo_dump| {
       let circle: Circle = ammo_dump.get_circle();
+      // Owned ammo dumps are filled with the color of their team
+      let fill_style: &JsValue = match ammo_dump.get_owner() {
+        Some(owner) => self.get_team_style(owner),
+        None => &self.fill_style,
+      };
+      context.set_fill_style(fill_style);
+      context.set_stroke_style(&self.stroke_style);
       context.begin_path();
       let _result =
         context.arc(circle.center_x, circle.center_y, circle.radius, 0., TAU);
       context.fill();
       context.stroke();
+      // A pie slice in the color of the capturing team shows the progress
+      if let Some((capturer, progress)) = ammo_dump.get_capture_progress() {
+        context.set_fill_style(self.get_team_style(capturer));
+        context.begin_path();
+        context.move_to(circle.center_x, circle.center_y);
+        let _result = context.arc(
+          circle.center_x,
+          circle.center_y,
+          circle.radius,
+          -FRAC_PI_2,
+          -FRAC_PI_2 + TAU * progress.min(1.),
+        );
+        context.close_path();
+        context.fill();
+      }
     });
   }
 }
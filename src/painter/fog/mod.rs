@@ -0,0 +1,94 @@
+// =============================================================================
+//! - Fog Painter for CroftSoft Mars
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::ai::perception::Perception;
+use crate::ai::tank_operator::TankOperator;
+use crate::constant::{
+  BOUNDS, FOG_CELL_SIZE, FOG_FILL_STYLE, FOG_VIEWER_COLOR,
+};
+use crate::model::obstacle::Obstacle;
+use crate::options::Options;
+use com_croftsoft_core::math::geom::point_2dd::Point2DD;
+use com_croftsoft_lib_role::Painter;
+use core::cell::{Ref, RefCell};
+use std::collections::VecDeque;
+use std::rc::Rc;
+use wasm_bindgen::JsValue;
+use web_sys::CanvasRenderingContext2d;
+
+// Shades the areas that the team of the player cannot see
+pub struct FogPainter {
+  context: Rc<RefCell<CanvasRenderingContext2d>>,
+  fill_style: JsValue,
+  obstacles: Rc<RefCell<VecDeque<Box<dyn Obstacle>>>>,
+  options: Rc<dyn Options>,
+  tank_operators: Rc<RefCell<VecDeque<Box<dyn TankOperator>>>>,
+}
+
+impl FogPainter {
+  pub fn new(
+    context: Rc<RefCell<CanvasRenderingContext2d>>,
+    obstacles: Rc<RefCell<VecDeque<Box<dyn Obstacle>>>>,
+    options: Rc<dyn Options>,
+    tank_operators: Rc<RefCell<VecDeque<Box<dyn TankOperator>>>>,
+  ) -> Self {
+    let fill_style: JsValue = JsValue::from_str(FOG_FILL_STYLE);
+    Self {
+      context,
+      fill_style,
+      obstacles,
+      options,
+      tank_operators,
+    }
+  }
+}
+
+impl Painter for FogPainter {
+  fn paint(&self) {
+    if !self.options.get_fog_display() {
+      return;
+    }
+    let viewers: Vec<Point2DD> = self
+      .tank_operators
+      .borrow()
+      .iter()
+      .map(|tank_operator| tank_operator.get_tank())
+      .filter(|tank| {
+        let tank = tank.borrow();
+        tank.is_active()
+          && !tank.is_burning()
+          && tank.get_color() == FOG_VIEWER_COLOR
+      })
+      .map(|tank| tank.borrow().get_center())
+      .collect();
+    let obstacles: Ref<VecDeque<Box<dyn Obstacle>>> = self.obstacles.borrow();
+    let context = self.context.borrow();
+    context.set_fill_style(&self.fill_style);
+    let mut cell_center = Point2DD::default();
+    let mut x: f64 = BOUNDS.x_min;
+    while x < BOUNDS.x_max {
+      let mut y: f64 = BOUNDS.y_min;
+      while y < BOUNDS.y_max {
+        cell_center.set_xy(x + FOG_CELL_SIZE / 2., y + FOG_CELL_SIZE / 2.);
+        if !viewers
+          .iter()
+          .any(|viewer| Perception::can_see(viewer, &cell_center, &obstacles))
+        {
+          context.fill_rect(x, y, FOG_CELL_SIZE, FOG_CELL_SIZE);
+        }
+        y += FOG_CELL_SIZE;
+      }
+      x += FOG_CELL_SIZE;
+    }
+  }
+}
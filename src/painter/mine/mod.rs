@@ -0,0 +1,76 @@
+// =============================================================================
+//! - Mine Painter for CroftSoft Mars
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constant::{
+  MINE_STROKE_STYLE, TANK_FILL_STYLE_BLUE, TANK_FILL_STYLE_RED,
+};
+use crate::model::mine::Mine;
+use crate::model::tank::Color;
+use com_croftsoft_core::math::geom::circle::Circle;
+use com_croftsoft_lib_role::Painter;
+use core::cell::RefCell;
+use core::f64::consts::TAU;
+use std::collections::VecDeque;
+use std::rc::Rc;
+use wasm_bindgen::JsValue;
+use web_sys::CanvasRenderingContext2d;
+
+pub struct MinePainter {
+  context: Rc<RefCell<CanvasRenderingContext2d>>,
+  fill_style_blue: JsValue,
+  fill_style_red: JsValue,
+  mines: Rc<RefCell<VecDeque<Box<dyn Mine>>>>,
+  stroke_style: JsValue,
+}
+
+impl MinePainter {
+  pub fn new(
+    context: Rc<RefCell<CanvasRenderingContext2d>>,
+    mines: Rc<RefCell<VecDeque<Box<dyn Mine>>>>,
+  ) -> Self {
+    let fill_style_blue: JsValue = JsValue::from_str(TANK_FILL_STYLE_BLUE);
+    let fill_style_red: JsValue = JsValue::from_str(TANK_FILL_STYLE_RED);
+    let stroke_style: JsValue = JsValue::from_str(MINE_STROKE_STYLE);
+    Self {
+      context,
+      fill_style_blue,
+      fill_style_red,
+      mines,
+      stroke_style,
+    }
+  }
+}
+
+impl Painter for MinePainter {
+  fn paint(&self) {
+    let context = self.context.borrow();
+    context.set_stroke_style(&self.stroke_style);
+    let mines = self.mines.borrow();
+    mines.iter().for_each(|mine| {
+      let circle: Circle = mine.get_circle();
+      context.begin_path();
+      let _result =
+        context.arc(circle.center_x, circle.center_y, circle.radius, 0., TAU);
+      // Mines are filled with the color of their team once armed
+      if mine.is_armed() {
+        let fill_style = match mine.get_color() {
+          Color::BLUE => &self.fill_style_blue,
+          Color::RED => &self.fill_style_red,
+        };
+        context.set_fill_style(fill_style);
+        context.fill();
+      }
+      context.stroke();
+    });
+  }
+}
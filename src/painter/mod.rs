@@ -1,9 +1,13 @@
 pub mod ammo_dump;
 pub mod bullet;
 pub mod explosion;
+pub mod fog;
+pub mod mine;
 pub mod node;
 pub mod obstacle;
 pub mod overlay;
 pub mod path;
 pub mod root;
+pub mod shell;
 pub mod tank;
+pub mod terrain;
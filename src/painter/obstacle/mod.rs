@@ -2,16 +2,18 @@
 //! - Obstacle Painter for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-03-15
-//! - Updated: 2023-09-04
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
-use crate::constant::{OBSTACLE_FILL_STYLE, OBSTACLE_STROKE_STYLE};
+use crate::constant::{
+  OBSTACLE_FILL_STYLE, OBSTACLE_STROKE_STYLE, WRECK_FILL_STYLE,
+};
 use crate::model::obstacle::Obstacle;
 use com_croftsoft_core::math::geom::circle::Circle;
 use com_croftsoft_lib_role::Painter;
@@ -27,6 +29,7 @@ pub struct ObstaclePainter {
   fill_style: JsValue,
   obstacles: Rc<RefCell<VecDeque<Box<dyn Obstacle>>>>,
   stroke_style: JsValue,
+  wreck_fill_style: JsValue,
 }
 
 impl ObstaclePainter {
@@ -36,11 +39,13 @@ impl ObstaclePainter {
   ) -> Self {
     let fill_style: JsValue = JsValue::from_str(OBSTACLE_FILL_STYLE);
     let stroke_style: JsValue = JsValue::from_str(OBSTACLE_STROKE_STYLE);
+    let wreck_fill_style: JsValue = JsValue::from_str(WRECK_FILL_STYLE);
     Self {
       context,
       fill_style,
       obstacles,
       stroke_style,
+      wreck_fill_style,
     }
   }
 }
@@ -48,10 +53,14 @@ impl ObstaclePainter {
 impl Painter for ObstaclePainter {
   fn paint(&self) {
     let context = self.context.borrow();
-    context.set_fill_style(&self.fill_style);
     context.set_stroke_style(&self.stroke_style);
     let obstacles: Ref<VecDeque<Box<dyn Obstacle>>> = self.obstacles.borrow();
     for obstacle in obstacles.iter() {
+      if obstacle.is_wreck() {
+        context.set_fill_style(&self.wreck_fill_style);
+      } else {
+        context.set_fill_style(&self.fill_style);
+      }
       let circle: Circle = obstacle.get_circle();
       context.begin_path();
       let _result =
@@ -2,10 +2,10 @@
 //! - Overlay Painter for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-03-13
-//! - Updated: 2023-09-04
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -55,5 +55,8 @@ impl Painter for OverlayPainter {
     context
       .fill_text(&overlay.update_rate_string, 4., 34.)
       .unwrap();
+    context
+      .fill_text(&overlay.tank_stuck_string, 4., 54.)
+      .unwrap();
   }
 }
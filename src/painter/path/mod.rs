@@ -2,10 +2,10 @@
 //! - Path Painter for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-06-18
-//! - Updated: 2023-09-04
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -13,7 +13,7 @@
 
 use crate::ai::state_space_node::StateSpaceNode;
 use crate::ai::tank_operator::TankOperator;
-use crate::constant::{TANK_FILL_STYLE_BLUE, TANK_FILL_STYLE_RED, TANK_RADIUS};
+use crate::constant::{TANK_FILL_STYLE_BLUE, TANK_FILL_STYLE_RED};
 use crate::model::tank::Color;
 use crate::options::Options;
 use com_croftsoft_lib_role::Painter;
@@ -71,7 +71,8 @@ impl PathPainter {
       let point_2dd = state_space_node.get_point_xy();
       // TODO: show state space node heading
       context.begin_path();
-      let _result = context.arc(point_2dd.x, point_2dd.y, TANK_RADIUS, 0., TAU);
+      let _result =
+        context.arc(point_2dd.x, point_2dd.y, tank.get_radius(), 0., TAU);
       context.stroke();
     });
     context.restore();
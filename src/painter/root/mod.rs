@@ -2,10 +2,10 @@
 //! - Root Painter for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-03-11
-//! - Updated: 2023-09-04
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -13,10 +13,14 @@
 
 use super::bullet::BulletPainter;
 use super::explosion::ExplosionPainter;
+use super::fog::FogPainter;
+use super::mine::MinePainter;
 use super::node::NodePainter;
 use super::obstacle::ObstaclePainter;
 use super::overlay::OverlayPainter;
+use super::shell::ShellPainter;
 use super::tank::TankPainter;
+use super::terrain::TerrainPainter;
 use super::{ammo_dump::AmmoDumpPainter, path::PathPainter};
 use crate::constant::BACKGROUND_FILL_STYLE;
 use crate::root::Root;
@@ -64,6 +68,13 @@ impl RootPainter {
     let explosion_painter =
       ExplosionPainter::new(context.clone(), world.get_explosions());
     let options = root.get_options();
+    let fog_painter = FogPainter::new(
+      context.clone(),
+      world.get_obstacles(),
+      options.clone(),
+      world.get_tank_operators(),
+    );
+    let mine_painter = MinePainter::new(context.clone(), world.get_mines());
     let node_painter: NodePainter = NodePainter::new(
       context.clone(),
       options.clone(),
@@ -75,16 +86,23 @@ impl RootPainter {
       OverlayPainter::new(context.clone(), options.clone(), root.get_overlay());
     let path_painter: PathPainter =
       PathPainter::new(context.clone(), options, world.get_tank_operators());
+    let shell_painter = ShellPainter::new(context.clone(), world.get_shells());
+    let terrain_painter =
+      TerrainPainter::new(context.clone(), world.get_terrain());
     let tank_painter: TankPainter =
       TankPainter::new(context, world.get_tank_operators());
     let painters: Vec<Box<dyn Painter>> = vec![
       Box::new(background_painter),
       // TODO: maybe wrap in a world painter
+      Box::new(terrain_painter),
       Box::new(ammo_dump_painter),
+      Box::new(mine_painter),
       Box::new(tank_painter),
       Box::new(obstacle_painter),
       Box::new(bullet_painter),
+      Box::new(shell_painter),
       Box::new(explosion_painter),
+      Box::new(fog_painter),
       Box::new(node_painter),
       Box::new(path_painter),
       Box::new(overlay_painter),
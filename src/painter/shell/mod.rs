@@ -0,0 +1,97 @@
+// =============================================================================
+//! - Shell Painter for CroftSoft Mars
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constant::{
+  SHELL_FILL_STYLE, SHELL_SHADOW_FILL_STYLE, SHELL_STROKE_STYLE,
+  SHELL_WARNING_STROKE_STYLE,
+};
+use crate::model::shell::Shell;
+use com_croftsoft_core::math::geom::circle::Circle;
+use com_croftsoft_lib_role::Painter;
+use core::cell::RefCell;
+use core::f64::consts::TAU;
+use std::collections::VecDeque;
+use std::rc::Rc;
+use wasm_bindgen::JsValue;
+use web_sys::CanvasRenderingContext2d;
+
+pub struct ShellPainter {
+  context: Rc<RefCell<CanvasRenderingContext2d>>,
+  fill_style: JsValue,
+  shadow_fill_style: JsValue,
+  shells: Rc<RefCell<VecDeque<Box<dyn Shell>>>>,
+  stroke_style: JsValue,
+  warning_stroke_style: JsValue,
+}
+
+impl ShellPainter {
+  pub fn new(
+    context: Rc<RefCell<CanvasRenderingContext2d>>,
+    shells: Rc<RefCell<VecDeque<Box<dyn Shell>>>>,
+  ) -> Self {
+    let fill_style: JsValue = JsValue::from_str(SHELL_FILL_STYLE);
+    let shadow_fill_style: JsValue = JsValue::from_str(SHELL_SHADOW_FILL_STYLE);
+    let stroke_style: JsValue = JsValue::from_str(SHELL_STROKE_STYLE);
+    let warning_stroke_style: JsValue =
+      JsValue::from_str(SHELL_WARNING_STROKE_STYLE);
+    Self {
+      context,
+      fill_style,
+      shadow_fill_style,
+      shells,
+      stroke_style,
+      warning_stroke_style,
+    }
+  }
+}
+
+impl Painter for ShellPainter {
+  fn paint(&self) {
+    let context = self.context.borrow();
+    let shells = self.shells.borrow();
+    shells.iter().for_each(|shell| {
+      // landing zone warning
+      let landing_circle: Circle = shell.get_landing_circle();
+      context.set_stroke_style(&self.warning_stroke_style);
+      context.begin_path();
+      let _result = context.arc(
+        landing_circle.center_x,
+        landing_circle.center_y,
+        landing_circle.radius,
+        0.,
+        TAU,
+      );
+      context.stroke();
+      // shadow on the ground
+      let circle: Circle = shell.get_circle();
+      context.set_fill_style(&self.shadow_fill_style);
+      context.begin_path();
+      let _result =
+        context.arc(circle.center_x, circle.center_y, circle.radius, 0., TAU);
+      context.fill();
+      // shell in the air
+      context.set_fill_style(&self.fill_style);
+      context.set_stroke_style(&self.stroke_style);
+      context.begin_path();
+      let _result = context.arc(
+        circle.center_x,
+        circle.center_y - shell.get_altitude(),
+        circle.radius,
+        0.,
+        TAU,
+      );
+      context.fill();
+      context.stroke();
+    });
+  }
+}
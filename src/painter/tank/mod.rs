@@ -2,10 +2,10 @@
 //! - Tank Painter for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-03-31
-//! - Updated: 2023-09-04
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -14,9 +14,9 @@
 use crate::ai::tank_operator::TankOperator;
 use crate::constant::{
   TANK_FILL_STYLE_BLUE, TANK_FILL_STYLE_RED, TANK_FILL_STYLE_SPARKING,
-  TANK_STROKE_STYLE,
+  TANK_RADIUS, TANK_STROKE_STYLE,
 };
-use crate::model::tank::{Color, Tank};
+use crate::model::tank::{Color, Tank, TankPart};
 use com_croftsoft_core::math::geom::circle::Circle;
 use com_croftsoft_lib_role::Painter;
 use core::cell::{Ref, RefCell};
@@ -55,6 +55,15 @@ impl TankPainter {
     }
   }
 
+  // True while the part is sparking from a hit or is out of action
+  fn is_showing_damage(
+    tank: &dyn Tank,
+    tank_part: TankPart,
+  ) -> bool {
+    (tank.is_sparking() && tank.get_damaged_part() == tank_part)
+      || tank.is_part_disabled(tank_part)
+  }
+
   fn paint_tank(
     &self,
     // TODO: Can this take TankAccessor
@@ -63,17 +72,19 @@ impl TankPainter {
     let circle: Circle = tank.get_circle();
     let center_x: f64 = circle.center_x;
     let center_y: f64 = circle.center_y;
+    // The drawing below is for a tank of the default radius
+    let scale: f64 = circle.radius / TANK_RADIUS;
     let context = self.context.borrow();
     context.save();
     let _result = context.translate(center_x, center_y);
     let _result = context.rotate(tank.get_body_heading());
+    let _result = context.scale(scale, scale);
     let fill_style = match tank.get_color() {
       Color::RED => &self.fill_style_red,
       Color::BLUE => &self.fill_style_blue,
     };
     context.set_fill_style(fill_style);
     context.set_stroke_style(&self.stroke_style);
-    // TODO: rescale this in terms of TANK_RADIUS
     // tank treads
     let x: f64 = -25.;
     let y: f64 = -25.;
@@ -118,10 +129,19 @@ impl TankPainter {
     context.begin_path();
     context.rect(x, y, w, h);
     context.fill();
+    // damaged treads
+    context.set_fill_style(&self.fill_style_sparking);
+    if TankPainter::is_showing_damage(tank, TankPart::TreadLeft) {
+      context.fill_rect(-25., -25., 50., 10.);
+    }
+    if TankPainter::is_showing_damage(tank, TankPart::TreadRight) {
+      context.fill_rect(-25., 15., 50., 10.);
+    }
     context.restore();
     context.save();
     context.translate(center_x, center_y)?;
     context.rotate(tank.get_turret_heading())?;
+    context.scale(scale, scale)?;
     // tank turret
     context.begin_path();
     context.arc(0., 0., 10., 0., TAU)?;
@@ -140,8 +160,16 @@ impl TankPainter {
     context.rect(14., -1., 11., 2.);
     context.fill();
     context.stroke();
+    // damaged turret
+    if TankPainter::is_showing_damage(tank, TankPart::Turret) {
+      context.set_fill_style(&self.fill_style_sparking);
+      context.begin_path();
+      context.arc(0., 0., 10., 0., TAU)?;
+      context.fill();
+    }
     // sparking
-    if tank.is_burning() || tank.is_sparking() {
+    if tank.is_burning() || TankPainter::is_showing_damage(tank, TankPart::Hull)
+    {
       context.set_fill_style(&self.fill_style_sparking);
       context.begin_path();
       context.rect(-5., -5., 10., 10.);
@@ -0,0 +1,75 @@
+// =============================================================================
+//! - Terrain Painter for CroftSoft Mars
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constant::TERRAIN_CELL_SIZE;
+use crate::world::terrain::{Terrain, TerrainKind};
+use com_croftsoft_core::math::geom::point_2dd::Point2DD;
+use com_croftsoft_lib_role::Painter;
+use core::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use wasm_bindgen::JsValue;
+use web_sys::CanvasRenderingContext2d;
+
+// Paints the terrain cells other than plain over the background
+pub struct TerrainPainter {
+  context: Rc<RefCell<CanvasRenderingContext2d>>,
+  kind_to_fill_style: HashMap<TerrainKind, JsValue>,
+  terrain: Rc<RefCell<Terrain>>,
+}
+
+impl TerrainPainter {
+  pub fn new(
+    context: Rc<RefCell<CanvasRenderingContext2d>>,
+    terrain: Rc<RefCell<Terrain>>,
+  ) -> Self {
+    let kind_to_fill_style: HashMap<TerrainKind, JsValue> = [
+      TerrainKind::Mud,
+      TerrainKind::Road,
+      TerrainKind::Rock,
+      TerrainKind::Sand,
+    ]
+    .into_iter()
+    .filter_map(|kind| {
+      kind
+        .get_fill_style()
+        .map(|fill_style| (kind, JsValue::from_str(fill_style)))
+    })
+    .collect();
+    Self {
+      context,
+      kind_to_fill_style,
+      terrain,
+    }
+  }
+}
+
+impl Painter for TerrainPainter {
+  fn paint(&self) {
+    let context = self.context.borrow();
+    let terrain = self.terrain.borrow();
+    for (index, kind) in terrain.get_cells().iter().enumerate() {
+      let Some(fill_style) = self.kind_to_fill_style.get(kind) else {
+        continue;
+      };
+      let origin: Point2DD = Terrain::get_cell_origin(index);
+      context.set_fill_style(fill_style);
+      context.fill_rect(
+        origin.x,
+        origin.y,
+        TERRAIN_CELL_SIZE,
+        TERRAIN_CELL_SIZE,
+      );
+    }
+  }
+}
@@ -0,0 +1,41 @@
+// =============================================================================
+//! - Mine Preparer for CroftSoft Mars
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::model::mine::Mine;
+use com_croftsoft_lib_role::Preparer;
+use core::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+pub struct MinePreparer {
+  mines: Rc<RefCell<VecDeque<Box<dyn Mine>>>>,
+}
+
+impl MinePreparer {
+  pub fn new(mines: Rc<RefCell<VecDeque<Box<dyn Mine>>>>) -> Self {
+    Self {
+      mines,
+    }
+  }
+}
+
+impl Preparer for MinePreparer {
+  fn prepare(&self) {
+    let length = self.mines.borrow().len();
+    for _ in 0..length {
+      let mut mine = self.mines.borrow_mut().pop_front().unwrap();
+      mine.prepare();
+      self.mines.borrow_mut().push_back(mine);
+    }
+  }
+}
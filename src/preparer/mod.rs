@@ -2,10 +2,10 @@
 //! - Preparer module for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-06-03
-//! - Updated: 2023-07-03
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -14,6 +14,8 @@
 pub mod ammo_dump;
 pub mod bullet;
 pub mod explosion;
+pub mod mine;
 pub mod obstacle;
+pub mod shell;
 pub mod tank;
 pub mod world;
@@ -0,0 +1,41 @@
+// =============================================================================
+//! - Shell Preparer for CroftSoft Mars
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::model::shell::Shell;
+use com_croftsoft_lib_role::Preparer;
+use core::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+pub struct ShellPreparer {
+  shells: Rc<RefCell<VecDeque<Box<dyn Shell>>>>,
+}
+
+impl ShellPreparer {
+  pub fn new(shells: Rc<RefCell<VecDeque<Box<dyn Shell>>>>) -> Self {
+    Self {
+      shells,
+    }
+  }
+}
+
+impl Preparer for ShellPreparer {
+  fn prepare(&self) {
+    let length = self.shells.borrow().len();
+    for _ in 0..length {
+      let mut shell = self.shells.borrow_mut().pop_front().unwrap();
+      shell.prepare();
+      self.shells.borrow_mut().push_back(shell);
+    }
+  }
+}
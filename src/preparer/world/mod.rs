@@ -2,10 +2,10 @@
 //! - World Preparer for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-05-13
-//! - Updated: 2023-09-04
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -13,7 +13,9 @@
 
 use crate::preparer::ammo_dump::AmmoDumpPreparer;
 use crate::preparer::bullet::BulletPreparer;
+use crate::preparer::mine::MinePreparer;
 use crate::preparer::obstacle::ObstaclePreparer;
+use crate::preparer::shell::ShellPreparer;
 use crate::preparer::tank::TankPreparer;
 use crate::world::World;
 use com_croftsoft_lib_role::Preparer;
@@ -27,13 +29,17 @@ impl WorldPreparer {
   pub fn new(world: Rc<dyn World>) -> Self {
     let ammo_dump_preparer = AmmoDumpPreparer::new(world.get_ammo_dumps());
     let bullet_preparer = BulletPreparer::new(world.get_bullets());
+    let mine_preparer = MinePreparer::new(world.get_mines());
     let obstacle_preparer = ObstaclePreparer::new(world.get_obstacles());
+    let shell_preparer = ShellPreparer::new(world.get_shells());
     let tank_preparer = TankPreparer::new(world.get_tank_operators());
     let child_preparers: Vec<Box<dyn Preparer>> = vec![
       Box::new(ammo_dump_preparer),
+      Box::new(mine_preparer),
       Box::new(tank_preparer),
       Box::new(obstacle_preparer),
       Box::new(bullet_preparer),
+      Box::new(shell_preparer),
     ];
     Self {
       child_preparers,
@@ -0,0 +1,44 @@
+// =============================================================================
+//! - Mine Updater for CroftSoft Mars
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constant::TIME_DELTA;
+use crate::model::mine::Mine;
+use com_croftsoft_lib_role::Updater;
+use core::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+pub struct MineUpdater {
+  mines: Rc<RefCell<VecDeque<Box<dyn Mine>>>>,
+}
+
+impl MineUpdater {
+  pub fn new(mines: Rc<RefCell<VecDeque<Box<dyn Mine>>>>) -> Self {
+    Self {
+      mines,
+    }
+  }
+}
+
+impl Updater for MineUpdater {
+  fn update(&self) {
+    let length: usize = self.mines.borrow().len();
+    for _index in 0..length {
+      let mut mine = self.mines.borrow_mut().pop_front().unwrap();
+      mine.update(TIME_DELTA);
+      if mine.is_active() {
+        self.mines.borrow_mut().push_back(mine);
+      }
+    }
+  }
+}
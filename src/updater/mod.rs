@@ -2,10 +2,13 @@ pub mod ammo_dump;
 pub mod bullet;
 pub mod explosion;
 pub mod game;
+pub mod mine;
 pub mod obstacle;
 pub mod options;
 pub mod overlay;
+pub mod perception;
 pub mod root;
+pub mod shell;
 pub mod tank;
 pub mod tank_operator;
 pub mod world;
@@ -2,10 +2,10 @@
 //! - Options Updater for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-03-13
-//! - Updated: 2023-09-04
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -17,6 +17,7 @@ use core::cell::{Ref, RefCell};
 use std::rc::Rc;
 
 pub trait OptionsUpdaterInputs {
+  fn get_fog_display_change_requested(&self) -> Option<bool>;
   fn get_node_display_change_requested(&self) -> Option<bool>;
   fn get_path_display_change_requested(&self) -> Option<bool>;
   fn get_pause_change_requested(&self) -> Option<bool>;
@@ -55,6 +56,9 @@ impl Updater for OptionsUpdater {
     {
       self.options.set_update_rate_display(frame_rate_display);
     }
+    if let Some(fog_display) = inputs.get_fog_display_change_requested() {
+      self.options.set_fog_display(fog_display);
+    }
     if let Some(node_display) = inputs.get_node_display_change_requested() {
       self.options.set_node_display(node_display);
     }
@@ -2,10 +2,10 @@
 //! - Overlay Updater for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-03-13
-//! - Updated: 2023-09-04
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -27,6 +27,8 @@ pub trait OverlayUpdaterEvents {
 
 pub trait OverlayUpdaterInputs {
   fn get_current_time_millis(&self) -> f64;
+  fn get_reset_requested(&self) -> bool;
+  fn get_tank_stuck_count(&self) -> usize;
   fn get_time_to_update(&self) -> bool;
   fn get_update_rate_display_change_requested(&self) -> Option<bool>;
 }
@@ -41,6 +43,10 @@ pub struct OverlayUpdater {
 }
 
 impl OverlayUpdater {
+  fn make_tank_stuck_string(tank_stuck_count: usize) -> String {
+    format!("Tanks stuck: {tank_stuck_count}")
+  }
+
   fn make_update_rate_string(&self) -> String {
     format!(
       "Updates per second: {:.3}",
@@ -74,6 +80,8 @@ impl OverlayUpdater {
     if self.options.get_pause() || !self.options.get_update_rate_display() {
       return;
     }
+    overlay.tank_stuck_string =
+      OverlayUpdater::make_tank_stuck_string(overlay.tank_stuck_count);
     overlay.update_rate_string = self.make_update_rate_string();
     // TODO: Only set updated to true when the overlay data changes
     self.events.borrow_mut().set_updated();
@@ -83,6 +91,11 @@ impl OverlayUpdater {
 impl Updater for OverlayUpdater {
   fn update(&self) {
     let inputs: Ref<dyn OverlayUpdaterInputs> = self.inputs.borrow();
+    // The stuck count is per level
+    if inputs.get_reset_requested() {
+      self.overlay.borrow_mut().tank_stuck_count = 0;
+    }
+    self.overlay.borrow_mut().tank_stuck_count += inputs.get_tank_stuck_count();
     if inputs.get_update_rate_display_change_requested().is_some()
     // inputs.get_bug_requested().is_some()
     //   || inputs.get_pause_change_requested().is_some()
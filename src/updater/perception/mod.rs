@@ -0,0 +1,43 @@
+// =============================================================================
+//! - Perception Updater for CroftSoft Mars
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constant::TIME_DELTA;
+use crate::world::World;
+use com_croftsoft_lib_role::Updater;
+use std::rc::Rc;
+
+pub struct PerceptionUpdater {
+  world: Rc<dyn World>,
+}
+
+impl PerceptionUpdater {
+  pub fn new(world: Rc<dyn World>) -> Self {
+    Self {
+      world,
+    }
+  }
+}
+
+impl Updater for PerceptionUpdater {
+  fn update(&self) {
+    let mines = self.world.get_mines();
+    let obstacles = self.world.get_obstacles();
+    let tank_operators = self.world.get_tank_operators();
+    self.world.get_perception().borrow_mut().update(
+      &mines.borrow(),
+      &obstacles.borrow(),
+      &tank_operators.borrow(),
+      TIME_DELTA,
+    );
+  }
+}
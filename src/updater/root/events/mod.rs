@@ -2,10 +2,10 @@
 //! - Root Updater Events for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-06-03
-//! - Updated: 2023-07-07
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -13,11 +13,17 @@
 
 use crate::events::Events;
 use crate::updater::overlay::OverlayUpdaterEvents;
+use crate::updater::world::WorldUpdaterEvents;
 use com_croftsoft_lib_animation::metronome::updater::MetronomeUpdaterEvents;
 use core::cell::RefCell;
 use std::rc::Rc;
 
 pub trait RootUpdaterEvents {
+  fn add_tank_stuck_count(
+    &mut self,
+    tank_stuck_count: usize,
+  );
+  fn get_tank_stuck_count(&self) -> usize;
   fn get_updated(&self) -> bool;
   fn get_time_to_update(&self) -> bool;
   fn get_update_period_millis_changed(&self) -> Option<f64>;
@@ -30,6 +36,17 @@ pub trait RootUpdaterEvents {
 }
 
 impl RootUpdaterEvents for Events {
+  fn add_tank_stuck_count(
+    &mut self,
+    tank_stuck_count: usize,
+  ) {
+    self.tank_stuck_count += tank_stuck_count;
+  }
+
+  fn get_tank_stuck_count(&self) -> usize {
+    self.tank_stuck_count
+  }
+
   fn get_time_to_update(&self) -> bool {
     self.time_to_update
   }
@@ -92,3 +109,15 @@ impl OverlayUpdaterEvents for RootUpdaterEventsAdapter {
     self.events.borrow_mut().set_updated();
   }
 }
+
+impl WorldUpdaterEvents for RootUpdaterEventsAdapter {
+  fn add_tank_stuck_count(
+    &mut self,
+    tank_stuck_count: usize,
+  ) {
+    self
+      .events
+      .borrow_mut()
+      .add_tank_stuck_count(tank_stuck_count);
+  }
+}
@@ -2,10 +2,10 @@
 //! - Root Updater Inputs for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-06-03
-//! - Updated: 2023-07-28
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -23,6 +23,7 @@ use std::rc::Rc;
 
 pub trait RootUpdaterInputs {
   fn get_current_time_millis(&self) -> f64;
+  fn get_fog_display_change_requested(&self) -> Option<bool>;
   fn get_node_display_change_requested(&self) -> Option<bool>;
   fn get_path_display_change_requested(&self) -> Option<bool>;
   fn get_pause_change_requested(&self) -> Option<bool>;
@@ -36,6 +37,10 @@ impl RootUpdaterInputs for Inputs {
     self.current_time_millis
   }
 
+  fn get_fog_display_change_requested(&self) -> Option<bool> {
+    self.fog_display_change_requested
+  }
+
   fn get_node_display_change_requested(&self) -> Option<bool> {
     self.node_display_change_requested
   }
@@ -118,6 +123,10 @@ impl MetronomeUpdaterInputs for RootUpdaterInputsAdapter {
 }
 
 impl OptionsUpdaterInputs for RootUpdaterInputsAdapter {
+  fn get_fog_display_change_requested(&self) -> Option<bool> {
+    self.inputs.borrow().get_fog_display_change_requested()
+  }
+
   fn get_node_display_change_requested(&self) -> Option<bool> {
     self.inputs.borrow().get_node_display_change_requested()
   }
@@ -167,9 +176,13 @@ impl OverlayUpdaterInputs for RootUpdaterInputsAdapter {
   //   self.inputs.borrow().get_pause_change_requested()
   // }
 
-  // fn get_reset_requested(&self) -> bool {
-  //   self.inputs.borrow().get_reset_requested()
-  // }
+  fn get_reset_requested(&self) -> bool {
+    self.inputs.borrow().get_reset_requested()
+  }
+
+  fn get_tank_stuck_count(&self) -> usize {
+    self.events.borrow().get_tank_stuck_count()
+  }
 
   fn get_time_to_update(&self) -> bool {
     self.events.borrow().get_time_to_update()
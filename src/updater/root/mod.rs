@@ -2,10 +2,10 @@
 //! - Root Updater for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-03-13
-//! - Updated: 2023-09-04
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -78,12 +78,13 @@ impl RootUpdater {
       time_millis_next_tick: 0.,
     }));
     let metronome_updater = MetronomeUpdater::new(
-      root_updater_events_adapter,
+      root_updater_events_adapter.clone(),
       root_updater_inputs_adapter.clone(),
       metronome,
     );
     let world_updater = WorldUpdater::new(
       configuration,
+      root_updater_events_adapter,
       root_updater_inputs_adapter,
       root.clone(),
     );
@@ -0,0 +1,44 @@
+// =============================================================================
+//! - Shell Updater for CroftSoft Mars
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constant::TIME_DELTA;
+use crate::model::shell::Shell;
+use com_croftsoft_lib_role::Updater;
+use core::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+pub struct ShellUpdater {
+  shells: Rc<RefCell<VecDeque<Box<dyn Shell>>>>,
+}
+
+impl ShellUpdater {
+  pub fn new(shells: Rc<RefCell<VecDeque<Box<dyn Shell>>>>) -> Self {
+    Self {
+      shells,
+    }
+  }
+}
+
+impl Updater for ShellUpdater {
+  fn update(&self) {
+    let length: usize = self.shells.borrow().len();
+    for _index in 0..length {
+      let mut shell = self.shells.borrow_mut().pop_front().unwrap();
+      shell.update(TIME_DELTA);
+      if shell.is_active() {
+        self.shells.borrow_mut().push_back(shell);
+      }
+    }
+  }
+}
@@ -2,10 +2,10 @@
 //! - Tank Updater for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-03-30
-//! - Updated: 2023-09-04
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -63,6 +63,12 @@ impl Updater for TankUpdater {
       tank.update(TIME_DELTA);
       if tank.is_active() {
         tank_operators.borrow_mut().push_back(tank_operator);
+      } else {
+        self
+          .world
+          .get_reservation_table()
+          .borrow_mut()
+          .release(tank_operator.get_id());
       }
     }
   }
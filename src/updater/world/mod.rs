@@ -2,10 +2,10 @@
 //! - World Updater for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-04-30
-//! - Updated: 2023-09-04
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -16,7 +16,10 @@ use crate::root::Root;
 use crate::updater::ammo_dump::AmmoDumpUpdater;
 use crate::updater::bullet::BulletUpdater;
 use crate::updater::explosion::ExplosionUpdater;
+use crate::updater::mine::MineUpdater;
 use crate::updater::obstacle::ObstacleUpdater;
+use crate::updater::perception::PerceptionUpdater;
+use crate::updater::shell::ShellUpdater;
 use crate::updater::tank::TankUpdater;
 use crate::updater::tank_operator::TankOperatorUpdater;
 use crate::visitor::bullet::BulletVisitor;
@@ -29,6 +32,13 @@ use com_croftsoft_lib_role::Updater;
 use core::cell::RefCell;
 use std::rc::Rc;
 
+pub trait WorldUpdaterEvents {
+  fn add_tank_stuck_count(
+    &mut self,
+    tank_stuck_count: usize,
+  );
+}
+
 pub trait WorldUpdaterInputs {
   fn get_reset_requested(&self) -> bool;
 }
@@ -36,6 +46,7 @@ pub trait WorldUpdaterInputs {
 pub struct WorldUpdater {
   child_updaters: Vec<Box<dyn Updater>>,
   configuration: Configuration,
+  events: Rc<RefCell<dyn WorldUpdaterEvents>>,
   inputs: Rc<RefCell<dyn WorldUpdaterInputs>>,
   root: Rc<dyn Root>,
   visitors: Vec<Box<dyn Visitor>>,
@@ -44,6 +55,7 @@ pub struct WorldUpdater {
 impl WorldUpdater {
   pub fn new(
     configuration: Configuration,
+    events: Rc<RefCell<dyn WorldUpdaterEvents>>,
     inputs: Rc<RefCell<dyn WorldUpdaterInputs>>,
     root: Rc<dyn Root>,
   ) -> Self {
@@ -51,33 +63,62 @@ impl WorldUpdater {
     let ammo_dump_updater = AmmoDumpUpdater::new(world.get_ammo_dumps());
     let bullet_updater = BulletUpdater::new(world.get_bullets());
     let explosion_updater = ExplosionUpdater::new(world.get_explosions());
+    let mine_updater = MineUpdater::new(world.get_mines());
     let obstacle_updater = ObstacleUpdater::new(world.get_obstacles());
+    let perception_updater = PerceptionUpdater::new(world.clone());
+    let shell_updater = ShellUpdater::new(world.get_shells());
     let tank_operator_updater = TankOperatorUpdater::new(world.clone());
     let tank_updater = TankUpdater::new(world.clone());
     let child_updaters: Vec<Box<dyn Updater>> = vec![
       Box::new(explosion_updater),
       Box::new(ammo_dump_updater),
+      Box::new(mine_updater),
+      Box::new(shell_updater),
+      Box::new(perception_updater),
       Box::new(tank_operator_updater),
       Box::new(tank_updater),
       Box::new(obstacle_updater),
       Box::new(bullet_updater),
     ];
-    let bullet_visitor = BulletVisitor::new(Rc::downgrade(&world));
+    let bullet_visitor = BulletVisitor::new(
+      Rc::downgrade(&root.get_factory()),
+      Rc::downgrade(&world),
+    );
     // TODO: add a collision detection visitor
     let explosion_visitor = ExplosionVisitor::new(Rc::downgrade(&world));
+    // The bullet visitor goes first so that the explosions of high-explosive
+    // bullets do damage before they start fading
     let visitors: Vec<Box<dyn Visitor>> = vec![
-      Box::new(explosion_visitor),
       Box::new(bullet_visitor),
+      Box::new(explosion_visitor),
     ];
     Self {
       child_updaters,
       configuration,
+      events,
       inputs,
       root,
       visitors,
     }
   }
 
+  fn publish_tank_stuck_count(&self) {
+    let tank_stuck_count: usize = self
+      .root
+      .get_world()
+      .get_tank_operators()
+      .borrow()
+      .iter()
+      .filter(|tank_operator| tank_operator.get_tank().borrow().is_stuck())
+      .count();
+    if tank_stuck_count > 0 {
+      self
+        .events
+        .borrow_mut()
+        .add_tank_stuck_count(tank_stuck_count);
+    }
+  }
+
   fn reset(&self) {
     let factory = Rc::downgrade(&self.root.get_factory());
     let world = Rc::downgrade(&self.root.get_world());
@@ -114,5 +155,6 @@ impl Updater for WorldUpdater {
     self.visitors.iter().for_each(|visitor| {
       self.root.get_world().accept_visitor(visitor.as_ref())
     });
+    self.publish_tank_stuck_count();
   }
 }
@@ -2,31 +2,77 @@
 //! - Bullet Visitor for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-06-04
-//! - Updated: 2023-07-28
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
 use super::Visitor;
-use crate::constant::OBSTACLE_BULLET_DAMAGE_MULTIPLIER;
+use crate::constant::{
+  BULLET_RICOCHET_INCIDENCE_ANGLE_MIN, OBSTACLE_BULLET_DAMAGE_MULTIPLIER,
+  WEAPON_HIGH_EXPLOSIVE_SPLASH_DAMAGE_FRACTION,
+  WEAPON_HIGH_EXPLOSIVE_SPLASH_RADIUS,
+};
 use crate::model::ammo_dump::AmmoDump;
+use crate::model::bullet::{Bullet, BulletKind};
+use crate::model::mine::Mine;
 use crate::model::obstacle::Obstacle;
 use crate::model::tank::Tank;
+use crate::world::factory::WorldFactory;
 use crate::world::World;
-use com_croftsoft_core::math::geom::circle::Circle;
+use com_croftsoft_core::math::geom::circle::{Circle, CircleAccessor};
 use std::rc::Weak;
 
 pub struct BulletVisitor {
+  factory: Weak<dyn WorldFactory>,
   world: Weak<dyn World>,
 }
 
 impl BulletVisitor {
-  pub fn new(world: Weak<dyn World>) -> Self {
+  // Spends the bullet and sets off the explosion of a high-explosive bullet
+  fn impact(
+    &self,
+    bullet: &mut Box<dyn Bullet>,
+  ) {
+    if bullet.get_kind() == BulletKind::HighExplosive {
+      let mut explosion_circle: Circle = bullet.get_circle();
+      explosion_circle.radius = WEAPON_HIGH_EXPLOSIVE_SPLASH_RADIUS;
+      let explosion = self.factory.upgrade().unwrap().make_explosion(
+        explosion_circle,
+        WEAPON_HIGH_EXPLOSIVE_SPLASH_DAMAGE_FRACTION * bullet.get_damage(),
+      );
+      self.world.upgrade().unwrap().add_explosion(explosion);
+    }
+    bullet.mark_spent();
+  }
+
+  // The angle between the reversed path of the bullet and the surface normal
+  // of the circle at the point where the bullet is, or None if the bullet is
+  // moving away from the circle
+  fn get_incidence_angle(
+    bullet: &dyn Bullet,
+    circle: &Circle,
+  ) -> Option<f64> {
+    let bullet_circle: Circle = bullet.get_circle();
+    let normal_heading: f64 = (bullet_circle.center_y - circle.center_y)
+      .atan2(bullet_circle.center_x - circle.center_x);
+    let cosine: f64 = -(bullet.get_heading() - normal_heading).cos();
+    if cosine < 0. {
+      return None;
+    }
+    Some(cosine.min(1.).acos())
+  }
+
+  pub fn new(
+    factory: Weak<dyn WorldFactory>,
+    world: Weak<dyn World>,
+  ) -> Self {
     Self {
+      factory,
       world,
     }
   }
@@ -53,12 +99,19 @@ impl Visitor for BulletVisitor {
       if damage <= 0. || !bullet.intersects_circle(&circle) {
         continue;
       }
-      bullet.mark_spent();
+      self.impact(bullet);
       ammo_dump.add_damage(damage);
       return;
     }
   }
 
+  // Bullets fly over mines
+  fn visit_mine(
+    &self,
+    _mine: &mut dyn Mine,
+  ) {
+  }
+
   fn visit_obstacle(
     &self,
     obstacle: &mut dyn Obstacle,
@@ -75,14 +128,27 @@ impl Visitor for BulletVisitor {
       .iter_mut()
     {
       let damage = bullet.get_damage();
-      if damage <= 0. {
+      // Armor-piercing bullets pass through obstacles
+      if damage <= 0. || bullet.get_kind() == BulletKind::ArmorPiercing {
         continue;
       }
       let circle: Circle = obstacle.get_circle();
       if !bullet.intersects_circle(&circle) {
         continue;
       }
-      bullet.mark_spent();
+      let Some(incidence_angle) =
+        BulletVisitor::get_incidence_angle(&**bullet, &circle)
+      else {
+        // Already glancing away
+        continue;
+      };
+      if bullet.get_kind() == BulletKind::Standard
+        && incidence_angle > BULLET_RICOCHET_INCIDENCE_ANGLE_MIN
+      {
+        bullet.ricochet(&circle);
+        continue;
+      }
+      self.impact(bullet);
       obstacle.add_damage(OBSTACLE_BULLET_DAMAGE_MULTIPLIER * damage);
       if !obstacle.is_active() {
         return;
@@ -110,8 +176,12 @@ impl Visitor for BulletVisitor {
       if damage <= 0. || !bullet.intersects_circle(&circle) {
         continue;
       }
-      bullet.mark_spent();
-      tank.add_damage(damage);
+      self.impact(bullet);
+      tank.add_hit(
+        damage,
+        bullet.get_heading(),
+        bullet.get_circle().get_center_point_2dd(),
+      );
       if !tank.is_active() {
         return;
       }
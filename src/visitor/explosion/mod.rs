@@ -2,42 +2,87 @@
 //! - Explosion Visitor for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-06-04
-//! - Updated: 2023-07-17
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
 use super::Visitor;
+use crate::constant::{
+  EXPLOSION_DAMAGE_EDGE_FRACTION, EXPLOSION_KNOCKBACK_SPEED_PER_DAMAGE,
+};
 use crate::model::ammo_dump::AmmoDump;
+use crate::model::explosion::ExplosionTarget;
+use crate::model::mine::Mine;
 use crate::model::obstacle::Obstacle;
 use crate::model::tank::Tank;
 use crate::world::World;
 use com_croftsoft_core::math::geom::circle::{Circle, CircleAccessor};
+use com_croftsoft_core::math::geom::point_2dd::Point2DD;
 use std::rc::Weak;
 
+// The damage and the outward push from the explosions that caught a target
+#[derive(Default)]
+struct ExplosionEffect {
+  damage: f64,
+  impulse_x: f64,
+  impulse_y: f64,
+}
+
 pub struct ExplosionVisitor {
   world: Weak<dyn World>,
 }
 
 impl ExplosionVisitor {
+  // Sums the effects of the explosions that have not yet damaged the target.
+  // The damage falls off with the distance from the center of the explosion
+  // to the nearest edge of the target.
   // TODO: add a Damageable argument when Rust supports trait upcasting
-  fn compute_explosion_damage(
+  fn compute_explosion_effect(
     &self,
-    circle: &dyn CircleAccessor,
-  ) -> f64 {
-    self
+    circle: &Circle,
+    explosion_target: ExplosionTarget,
+  ) -> ExplosionEffect {
+    let target_center: Point2DD = circle.get_center_point_2dd();
+    let mut explosion_effect = ExplosionEffect::default();
+    for explosion in self
       .world
       .upgrade()
       .unwrap()
       .get_explosions()
-      .borrow()
-      .iter()
-      .filter(|explosion| explosion.intersects_circle(circle))
-      .fold(0., |damage, explosion| damage + explosion.get_damage())
+      .borrow_mut()
+      .iter_mut()
+    {
+      let damage: f64 = explosion.get_damage();
+      if damage <= 0.
+        || explosion.has_damaged(explosion_target)
+        || !explosion.intersects_circle(circle)
+      {
+        continue;
+      }
+      explosion.mark_damaged(explosion_target);
+      let explosion_circle: Circle = explosion.get_circle();
+      let explosion_center: Point2DD = explosion_circle.get_center_point_2dd();
+      let distance: f64 = explosion_center.distance_to(&target_center);
+      let edge_distance: f64 = (distance - circle.radius).max(0.);
+      let falloff: f64 = 1.
+        - (1. - EXPLOSION_DAMAGE_EDGE_FRACTION)
+          * (edge_distance / explosion_circle.radius).min(1.);
+      let damage_received: f64 = falloff * damage;
+      explosion_effect.damage += damage_received;
+      if distance > 0. {
+        let speed: f64 = EXPLOSION_KNOCKBACK_SPEED_PER_DAMAGE * damage_received;
+        explosion_effect.impulse_x +=
+          speed * (target_center.x - explosion_center.x) / distance;
+        explosion_effect.impulse_y +=
+          speed * (target_center.y - explosion_center.y) / distance;
+      }
+    }
+    explosion_effect
   }
 
   pub fn new(world: Weak<dyn World>) -> Self {
@@ -53,19 +98,42 @@ impl Visitor for ExplosionVisitor {
     ammo_dump: &mut dyn AmmoDump,
   ) {
     let circle: Circle = ammo_dump.get_circle();
-    let damage: f64 = self.compute_explosion_damage(&circle);
-    ammo_dump.add_damage(damage);
+    let explosion_effect: ExplosionEffect = self.compute_explosion_effect(
+      &circle,
+      ExplosionTarget::AmmoDump(ammo_dump.get_id()),
+    );
+    ammo_dump.add_damage(explosion_effect.damage);
   }
 
   // TODO: visit_bullet() could change bullet velocity if inside an explosion
 
+  // Mines caught in an explosion detonate in turn
+  fn visit_mine(
+    &self,
+    mine: &mut dyn Mine,
+  ) {
+    let circle: Circle = mine.get_circle();
+    let explosion_effect: ExplosionEffect = self
+      .compute_explosion_effect(&circle, ExplosionTarget::Mine(mine.get_id()));
+    mine.add_damage(explosion_effect.damage);
+  }
+
   fn visit_obstacle(
     &self,
     obstacle: &mut dyn Obstacle,
   ) {
     let circle: Circle = obstacle.get_circle();
-    let damage: f64 = self.compute_explosion_damage(&circle);
-    obstacle.add_damage(damage);
+    // Wrecks and obstacles have separate ID sequences
+    let explosion_target: ExplosionTarget = if obstacle.is_wreck() {
+      ExplosionTarget::Wreck(obstacle.get_id())
+    } else {
+      ExplosionTarget::Obstacle(obstacle.get_id())
+    };
+    let explosion_effect: ExplosionEffect =
+      self.compute_explosion_effect(&circle, explosion_target);
+    obstacle.add_damage(explosion_effect.damage);
+    obstacle
+      .add_impulse(explosion_effect.impulse_x, explosion_effect.impulse_y);
   }
 
   fn visit_tank(
@@ -73,7 +141,9 @@ impl Visitor for ExplosionVisitor {
     tank: &mut dyn Tank,
   ) {
     let circle: Circle = tank.get_circle();
-    let damage: f64 = self.compute_explosion_damage(&circle);
-    tank.add_damage(damage);
+    let explosion_effect: ExplosionEffect = self
+      .compute_explosion_effect(&circle, ExplosionTarget::Tank(tank.get_id()));
+    tank.add_damage(explosion_effect.damage);
+    tank.add_impulse(explosion_effect.impulse_x, explosion_effect.impulse_y);
   }
 }
@@ -2,16 +2,17 @@
 //! - Visitor for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-06-04
-//! - Updated: 2023-07-03
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
 use crate::model::ammo_dump::AmmoDump;
+use crate::model::mine::Mine;
 use crate::model::obstacle::Obstacle;
 use crate::model::tank::Tank;
 
@@ -24,6 +25,11 @@ pub trait Visitor {
     ammo_dump: &mut dyn AmmoDump,
   );
 
+  fn visit_mine(
+    &self,
+    mine: &mut dyn Mine,
+  );
+
   fn visit_obstacle(
     &self,
     obstacle: &mut dyn Obstacle,
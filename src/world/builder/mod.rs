@@ -2,10 +2,10 @@
 //! - World Builder for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-05-02
-//! - Updated: 2023-07-28
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -13,10 +13,11 @@
 
 use super::factory::WorldFactory;
 use super::World;
+use crate::ai::ai_profile::AiProfile;
 use crate::ai::tank_operator::default::DefaultTankOperator;
-use crate::constant::{AMMO_DUMP_AMMO_MAX, OBSTACLE_RADIUS_MIN};
+use crate::constant::AMMO_DUMP_AMMO_MAX;
 use crate::model::ammo_dump::default::DefaultAmmoDump;
-use crate::model::obstacle::default::DefaultObstacle;
+use crate::model::tank::archetype::TankArchetype;
 use crate::model::tank::default::DefaultTank;
 use crate::model::tank::{Color, Tank};
 use crate::model::ModelAccessor;
@@ -31,6 +32,8 @@ pub struct WorldBuilder {
 }
 
 pub struct WorldBuilderTankConfig {
+  pub ai_profile: AiProfile,
+  pub archetype: TankArchetype,
   pub body_heading: f64,
   pub center_x: f64,
   pub center_y: f64,
@@ -66,20 +69,14 @@ impl WorldBuilder {
     &self,
     circle: Circle,
     drift_bounds: Rectangle,
-    id: usize,
   ) {
-    let obstacle = DefaultObstacle::new(
+    let obstacle = self.factory.upgrade().unwrap().make_obstacle(
       circle,
       drift_bounds,
-      id,
-      OBSTACLE_RADIUS_MIN,
+      self.factory.clone(),
       self.world.clone(),
     );
-    self
-      .world
-      .upgrade()
-      .unwrap()
-      .add_obstacle(Box::new(obstacle));
+    self.world.upgrade().unwrap().add_obstacle(obstacle);
   }
 
   pub fn build_tank_operator(
@@ -87,6 +84,8 @@ impl WorldBuilder {
     tank_config: WorldBuilderTankConfig,
   ) {
     let WorldBuilderTankConfig {
+      ai_profile,
+      archetype,
       body_heading,
       center_x,
       center_y,
@@ -96,6 +95,7 @@ impl WorldBuilder {
     } = tank_config;
     let tank: Rc<RefCell<DefaultTank>> =
       Rc::new(RefCell::new(DefaultTank::new(
+        archetype,
         center_x,
         center_y,
         color,
@@ -106,6 +106,7 @@ impl WorldBuilder {
     tank.borrow_mut().set_body_heading(body_heading);
     tank.borrow_mut().set_turret_heading(turret_heading);
     let tank_operator = DefaultTankOperator::new(
+      ai_profile,
       tank.borrow().get_id(),
       tank.clone(),
       self.world.clone(),
@@ -2,22 +2,27 @@
 //! - World for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-04-29
-//! - Updated: 2023-08-02
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
 use super::World;
+use crate::ai::perception::Perception;
+use crate::ai::reservation_table::ReservationTable;
 use crate::ai::tank_operator::TankOperator;
 use crate::model::ammo_dump::AmmoDump;
 use crate::model::bullet::Bullet;
 use crate::model::explosion::Explosion;
+use crate::model::mine::Mine;
 use crate::model::obstacle::Obstacle;
+use crate::model::shell::Shell;
 use crate::visitor::{Visitor, VisitorAcceptor};
+use crate::world::terrain::Terrain;
 use com_croftsoft_core::math::geom::circle::{Circle, CircleAccessor};
 use com_croftsoft_core::math::geom::point_2dd::Point2DD;
 use core::cell::RefCell;
@@ -29,8 +34,13 @@ pub struct DefaultWorld {
   ammo_dumps: Rc<RefCell<VecDeque<Box<dyn AmmoDump>>>>,
   bullets: Rc<RefCell<VecDeque<Box<dyn Bullet>>>>,
   explosions: Rc<RefCell<VecDeque<Box<dyn Explosion>>>>,
+  mines: Rc<RefCell<VecDeque<Box<dyn Mine>>>>,
   obstacles: Rc<RefCell<VecDeque<Box<dyn Obstacle>>>>,
+  perception: Rc<RefCell<Perception>>,
+  reservation_table: Rc<RefCell<ReservationTable>>,
+  shells: Rc<RefCell<VecDeque<Box<dyn Shell>>>>,
   tank_operators: Rc<RefCell<VecDeque<Box<dyn TankOperator>>>>,
+  terrain: Rc<RefCell<Terrain>>,
 }
 
 impl VisitorAcceptor for DefaultWorld {
@@ -41,6 +51,9 @@ impl VisitorAcceptor for DefaultWorld {
     for ammo_dump in self.ammo_dumps.borrow_mut().iter_mut() {
       visitor.visit_ammo_dump(ammo_dump.as_mut());
     }
+    for mine in self.mines.borrow_mut().iter_mut() {
+      visitor.visit_mine(mine.as_mut());
+    }
     for obstacle in self.obstacles.borrow_mut().iter_mut() {
       visitor.visit_obstacle(obstacle.as_mut());
     }
@@ -73,6 +86,13 @@ impl World for DefaultWorld {
     self.explosions.borrow_mut().push_back(explosion);
   }
 
+  fn add_mine(
+    &self,
+    mine: Box<dyn Mine>,
+  ) {
+    self.mines.borrow_mut().push_back(mine);
+  }
+
   fn add_obstacle(
     &self,
     obstacle: Box<dyn Obstacle>,
@@ -80,6 +100,13 @@ impl World for DefaultWorld {
     self.obstacles.borrow_mut().push_back(obstacle);
   }
 
+  fn add_shell(
+    &self,
+    shell: Box<dyn Shell>,
+  ) {
+    self.shells.borrow_mut().push_back(shell);
+  }
+
   fn add_tank_operator(
     &self,
     tank_operator: Box<dyn TankOperator>,
@@ -91,8 +118,13 @@ impl World for DefaultWorld {
     self.ammo_dumps.borrow_mut().clear();
     self.bullets.borrow_mut().clear();
     self.explosions.borrow_mut().clear();
+    self.mines.borrow_mut().clear();
     self.obstacles.borrow_mut().clear();
+    self.perception.borrow_mut().clear();
+    self.reservation_table.borrow_mut().clear();
+    self.shells.borrow_mut().clear();
     self.tank_operators.borrow_mut().clear();
+    self.terrain.borrow_mut().clear();
   }
 
   fn get_ammo_dumps(&self) -> Rc<RefCell<VecDeque<Box<dyn AmmoDump>>>> {
@@ -111,6 +143,7 @@ impl World for DefaultWorld {
       .obstacles
       .borrow()
       .iter()
+      .filter(|obstacle| !obstacle.is_wreck())
       .map(|obstacle| obstacle.get_circle())
       .fold(None, |closest, circle| {
         if let Some(closest_circle) = closest {
@@ -132,14 +165,34 @@ impl World for DefaultWorld {
     self.explosions.clone()
   }
 
+  fn get_mines(&self) -> Rc<RefCell<VecDeque<Box<dyn Mine>>>> {
+    self.mines.clone()
+  }
+
   fn get_obstacles(&self) -> Rc<RefCell<VecDeque<Box<dyn Obstacle>>>> {
     self.obstacles.clone()
   }
 
+  fn get_perception(&self) -> Rc<RefCell<Perception>> {
+    self.perception.clone()
+  }
+
+  fn get_reservation_table(&self) -> Rc<RefCell<ReservationTable>> {
+    self.reservation_table.clone()
+  }
+
+  fn get_shells(&self) -> Rc<RefCell<VecDeque<Box<dyn Shell>>>> {
+    self.shells.clone()
+  }
+
   fn get_tank_operators(&self) -> Rc<RefCell<VecDeque<Box<dyn TankOperator>>>> {
     self.tank_operators.clone()
   }
 
+  fn get_terrain(&self) -> Rc<RefCell<Terrain>> {
+    self.terrain.clone()
+  }
+
   fn is_blocked_by_ammo_dump(
     &self,
     circle: &dyn CircleAccessor,
@@ -2,10 +2,10 @@
 //! - World Builder Director for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-05-03
-//! - Updated: 2023-07-28
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -14,12 +14,14 @@
 use super::builder::{WorldBuilder, WorldBuilderTankConfig};
 use super::seed::WorldSeed;
 use super::World;
+use crate::ai::ai_profile::AiProfile;
 use crate::constant::{
   AMMO_DUMP_AMMO_MAX, AMMO_DUMP_COUNT_MAXIMUM,
   AMMO_DUMP_RANDOM_PLACEMENT_ATTEMPTS_MAX, OBSTACLE_COUNT_MAXIMUM,
   OBSTACLE_RADIUS_MAX, OBSTACLE_RADIUS_MIN,
   OBSTACLE_RANDOM_PLACEMENT_ATTEMPTS_MAX, TANK_COUNT_MAXIMUM,
 };
+use crate::model::tank::archetype::TankArchetype;
 use crate::model::tank::Color;
 use com_croftsoft_core::math::geom::circle::Circle;
 use core::f64::consts::FRAC_PI_2;
@@ -36,6 +38,7 @@ pub struct WorldBuilderDirector {
 impl WorldBuilderDirector {
   pub fn direct(&self) {
     self.world_builder.world.upgrade().unwrap().clear();
+    self.direct_terrain();
     self.direct_tank_operators();
     self.direct_obstacles();
     self.direct_ammo_dumps();
@@ -76,7 +79,7 @@ impl WorldBuilderDirector {
     let radius_uniform =
       Uniform::from(OBSTACLE_RADIUS_MIN..=OBSTACLE_RADIUS_MAX);
     let obstacle_count = OBSTACLE_COUNT_MAXIMUM.min(self.seed.level);
-    for index in 0..obstacle_count {
+    for _ in 0..obstacle_count {
       let mut circle = Circle {
         center_x: 0.,
         center_y: 0.,
@@ -98,16 +101,33 @@ impl WorldBuilderDirector {
           break;
         }
       }
-      self
-        .world_builder
-        .build_obstacle(circle, self.seed.bounds, index);
+      self.world_builder.build_obstacle(circle, self.seed.bounds);
     }
   }
 
+  fn direct_terrain(&self) {
+    self
+      .world_builder
+      .world
+      .upgrade()
+      .unwrap()
+      .get_terrain()
+      .borrow_mut()
+      .generate();
+  }
+
   fn direct_tank_operators(&self) {
     let heading_blue = -FRAC_PI_2;
     let heading_red = FRAC_PI_2;
     let tank_count = TANK_COUNT_MAXIMUM.min(self.seed.level);
+    // Each level adds another archetype to the mix until all are in play
+    let archetypes: [TankArchetype; 4] = [
+      TankArchetype::MEDIUM,
+      TankArchetype::SCOUT,
+      TankArchetype::HEAVY,
+      TankArchetype::ARTILLERY,
+    ];
+    let archetype_count: usize = archetypes.len().min(self.seed.level);
     for index in 0..tank_count {
       let spacer_index = (index + 1) / 2;
       let delta_x: i64 = if index % 2 == 0 {
@@ -115,9 +135,13 @@ impl WorldBuilderDirector {
       } else {
         spacer_index as i64 * -100
       };
+      let ai_profile: AiProfile = AiProfile::for_level(index, self.seed.level);
+      let archetype: TankArchetype = archetypes[index % archetype_count];
       self
         .world_builder
         .build_tank_operator(WorldBuilderTankConfig {
+          ai_profile,
+          archetype,
           body_heading: heading_blue,
           center_x: (300 + delta_x) as f64,
           center_y: 500.,
@@ -128,6 +152,8 @@ impl WorldBuilderDirector {
       self
         .world_builder
         .build_tank_operator(WorldBuilderTankConfig {
+          ai_profile,
+          archetype,
           body_heading: heading_red,
           center_x: (300 - delta_x) as f64,
           center_y: 100.,
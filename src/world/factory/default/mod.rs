@@ -2,43 +2,61 @@
 //! - Default World Factory for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-05-17
-//! - Updated: 2023-06-15
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
 use super::WorldFactory;
+use crate::constant::{OBSTACLE_JERK_MAGNITUDE_MAX, OBSTACLE_RADIUS_MIN};
 use crate::model::bullet::default::DefaultBullet;
-use crate::model::bullet::Bullet;
+use crate::model::bullet::{Bullet, BulletConfig};
 use crate::model::explosion::default::DefaultExplosion;
 use crate::model::explosion::Explosion;
+use crate::model::mine::default::DefaultMine;
+use crate::model::mine::Mine;
+use crate::model::obstacle::default::DefaultObstacle;
+use crate::model::obstacle::wreck::WreckObstacle;
+use crate::model::obstacle::Obstacle;
+use crate::model::shell::default::DefaultShell;
+use crate::model::shell::Shell;
+use crate::model::tank::Color;
 use crate::world::default::DefaultWorld;
 use crate::world::World;
 use com_croftsoft_core::math::geom::circle::Circle;
+use com_croftsoft_core::math::geom::point_2dd::Point2DD;
+use com_croftsoft_core::math::geom::rectangle::Rectangle;
 use core::cell::Cell;
-use std::rc::Rc;
+use std::rc::{Rc, Weak};
 
 #[derive(Default)]
 pub struct DefaultWorldFactory {
   // TODO: maybe use an atomic instead of Cell for interior mutability
   id_next_bullet: Cell<usize>,
   id_next_explosion: Cell<usize>,
+  id_next_mine: Cell<usize>,
+  id_next_obstacle: Cell<usize>,
+  id_next_shell: Cell<usize>,
+  id_next_wreck: Cell<usize>,
 }
 
 impl WorldFactory for DefaultWorldFactory {
   fn make_bullet(
     &self,
+    bullet_config: BulletConfig,
     heading: f64,
     origin_x: f64,
     origin_y: f64,
+    world: Weak<dyn World>,
   ) -> Box<dyn Bullet> {
     let id = self.id_next_bullet.get();
     self.id_next_bullet.set(id + 1);
-    let bullet = DefaultBullet::new(heading, id, origin_x, origin_y);
+    let bullet =
+      DefaultBullet::new(bullet_config, heading, id, origin_x, origin_y, world);
     Box::new(bullet)
   }
 
@@ -53,6 +71,66 @@ impl WorldFactory for DefaultWorldFactory {
     Box::new(explosion)
   }
 
+  fn make_mine(
+    &self,
+    center_x: f64,
+    center_y: f64,
+    color: Color,
+    factory: Weak<dyn WorldFactory>,
+    world: Weak<dyn World>,
+  ) -> Box<dyn Mine> {
+    let id = self.id_next_mine.get();
+    self.id_next_mine.set(id + 1);
+    let mine = DefaultMine::new(center_x, center_y, color, factory, id, world);
+    Box::new(mine)
+  }
+
+  fn make_obstacle(
+    &self,
+    circle: Circle,
+    drift_bounds: Rectangle,
+    factory: Weak<dyn WorldFactory>,
+    world: Weak<dyn World>,
+  ) -> Box<dyn Obstacle> {
+    let id = self.id_next_obstacle.get();
+    self.id_next_obstacle.set(id + 1);
+    let obstacle = DefaultObstacle::new(
+      circle,
+      drift_bounds,
+      factory,
+      id,
+      OBSTACLE_JERK_MAGNITUDE_MAX,
+      OBSTACLE_RADIUS_MIN,
+      world,
+    );
+    Box::new(obstacle)
+  }
+
+  fn make_shell(
+    &self,
+    factory: Weak<dyn WorldFactory>,
+    origin: Point2DD,
+    target: Point2DD,
+    world: Weak<dyn World>,
+  ) -> Box<dyn Shell> {
+    let id = self.id_next_shell.get();
+    self.id_next_shell.set(id + 1);
+    let shell = DefaultShell::new(factory, id, origin, target, world);
+    Box::new(shell)
+  }
+
+  fn make_wreck(
+    &self,
+    circle: Circle,
+    factory: Weak<dyn WorldFactory>,
+    world: Weak<dyn World>,
+  ) -> Box<dyn Obstacle> {
+    let id = self.id_next_wreck.get();
+    self.id_next_wreck.set(id + 1);
+    let wreck = WreckObstacle::new(circle, factory, id, world);
+    Box::new(wreck)
+  }
+
   fn make_world(&self) -> Rc<dyn World> {
     Rc::new(DefaultWorld::default())
   }
@@ -2,29 +2,38 @@
 //! - World Factory trait for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-05-17
-//! - Updated: 2023-06-15
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
 use super::World;
-use crate::model::bullet::Bullet;
+use crate::model::bullet::{Bullet, BulletConfig};
 use crate::model::explosion::Explosion;
+use crate::model::mine::Mine;
+use crate::model::obstacle::Obstacle;
+use crate::model::shell::Shell;
+use crate::model::tank::Color;
 use com_croftsoft_core::math::geom::circle::Circle;
-use std::rc::Rc;
+use com_croftsoft_core::math::geom::point_2dd::Point2DD;
+use com_croftsoft_core::math::geom::rectangle::Rectangle;
+use std::rc::{Rc, Weak};
 
 pub mod default;
 
 pub trait WorldFactory {
+  // The bullet keeps the world to check the terrain it flies over
   fn make_bullet(
     &self,
+    bullet_config: BulletConfig,
     heading: f64,
     origin_x: f64,
     origin_y: f64,
+    world: Weak<dyn World>,
   ) -> Box<dyn Bullet>;
 
   fn make_explosion(
@@ -33,5 +42,41 @@ pub trait WorldFactory {
     damage: f64,
   ) -> Box<dyn Explosion>;
 
+  // The mine keeps the factory and the world to set off its explosion
+  fn make_mine(
+    &self,
+    center_x: f64,
+    center_y: f64,
+    color: Color,
+    factory: Weak<dyn WorldFactory>,
+    world: Weak<dyn World>,
+  ) -> Box<dyn Mine>;
+
+  // The obstacle keeps the factory and the world to split into fragments
+  fn make_obstacle(
+    &self,
+    circle: Circle,
+    drift_bounds: Rectangle,
+    factory: Weak<dyn WorldFactory>,
+    world: Weak<dyn World>,
+  ) -> Box<dyn Obstacle>;
+
+  // The shell keeps the factory and the world to set off its explosion
+  fn make_shell(
+    &self,
+    factory: Weak<dyn WorldFactory>,
+    origin: Point2DD,
+    target: Point2DD,
+    world: Weak<dyn World>,
+  ) -> Box<dyn Shell>;
+
+  // The wreck keeps the factory and the world to set off its explosion
+  fn make_wreck(
+    &self,
+    circle: Circle,
+    factory: Weak<dyn WorldFactory>,
+    world: Weak<dyn World>,
+  ) -> Box<dyn Obstacle>;
+
   fn make_world(&self) -> Rc<dyn World>;
 }
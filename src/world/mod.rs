@@ -2,21 +2,26 @@
 //! - World trait for CroftSoft Mars
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-05-20
-//! - Updated: 2023-08-02
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
+use crate::ai::perception::Perception;
+use crate::ai::reservation_table::ReservationTable;
 use crate::ai::tank_operator::TankOperator;
 use crate::model::ammo_dump::AmmoDump;
 use crate::model::bullet::Bullet;
 use crate::model::explosion::Explosion;
+use crate::model::mine::Mine;
 use crate::model::obstacle::Obstacle;
+use crate::model::shell::Shell;
 use crate::visitor::VisitorAcceptor;
+use crate::world::terrain::Terrain;
 use com_croftsoft_core::math::geom::circle::{Circle, CircleAccessor};
 use com_croftsoft_core::math::geom::point_2dd::Point2DD;
 use core::cell::RefCell;
@@ -28,6 +33,7 @@ pub mod default;
 pub mod director;
 pub mod factory;
 pub mod seed;
+pub mod terrain;
 
 pub trait World: VisitorAcceptor {
   fn add_ammo_dump(
@@ -45,11 +51,21 @@ pub trait World: VisitorAcceptor {
     explosion: Box<dyn Explosion>,
   );
 
+  fn add_mine(
+    &self,
+    mine: Box<dyn Mine>,
+  );
+
   fn add_obstacle(
     &self,
     obstacle: Box<dyn Obstacle>,
   );
 
+  fn add_shell(
+    &self,
+    shell: Box<dyn Shell>,
+  );
+
   fn add_tank_operator(
     &self,
     tank_operator: Box<dyn TankOperator>,
@@ -68,10 +84,20 @@ pub trait World: VisitorAcceptor {
 
   fn get_explosions(&self) -> Rc<RefCell<VecDeque<Box<dyn Explosion>>>>;
 
+  fn get_mines(&self) -> Rc<RefCell<VecDeque<Box<dyn Mine>>>>;
+
   fn get_obstacles(&self) -> Rc<RefCell<VecDeque<Box<dyn Obstacle>>>>;
 
+  fn get_perception(&self) -> Rc<RefCell<Perception>>;
+
+  fn get_reservation_table(&self) -> Rc<RefCell<ReservationTable>>;
+
+  fn get_shells(&self) -> Rc<RefCell<VecDeque<Box<dyn Shell>>>>;
+
   fn get_tank_operators(&self) -> Rc<RefCell<VecDeque<Box<dyn TankOperator>>>>;
 
+  fn get_terrain(&self) -> Rc<RefCell<Terrain>>;
+
   fn is_blocked_by_ammo_dump(
     &self,
     circle: &dyn CircleAccessor,
@@ -0,0 +1,205 @@
+// =============================================================================
+//! - Terrain for CroftSoft Mars
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constant::{
+  BOUNDS, TERRAIN_CELL_SIZE, TERRAIN_MUD_BULLET_DRAG, TERRAIN_MUD_FILL_STYLE,
+  TERRAIN_MUD_NOISE_MAX, TERRAIN_MUD_SPEED_MULTIPLIER, TERRAIN_NOISE_CELL_SIZE,
+  TERRAIN_ROAD_COUNT, TERRAIN_ROAD_FILL_STYLE, TERRAIN_ROAD_SPEED_MULTIPLIER,
+  TERRAIN_ROCK_FILL_STYLE, TERRAIN_ROCK_NOISE_MIN,
+  TERRAIN_ROCK_SPEED_MULTIPLIER, TERRAIN_SAND_BULLET_DRAG,
+  TERRAIN_SAND_FILL_STYLE, TERRAIN_SAND_NOISE_MIN,
+  TERRAIN_SAND_SPEED_MULTIPLIER,
+};
+use com_croftsoft_core::math::geom::point_2dd::Point2DD;
+use rand::rngs::ThreadRng;
+use rand::Rng;
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum TerrainKind {
+  Mud,
+  // Drawn as the background
+  #[default]
+  Plain,
+  Road,
+  Rock,
+  Sand,
+}
+
+impl TerrainKind {
+  // The fastest terrain for keeping path cost estimates from being too high
+  pub const SPEED_MULTIPLIER_MAX: f64 = TERRAIN_ROAD_SPEED_MULTIPLIER;
+
+  // Soft ground catches low bullets and shortens their range
+  pub fn get_bullet_drag(&self) -> f64 {
+    match self {
+      TerrainKind::Mud => TERRAIN_MUD_BULLET_DRAG,
+      TerrainKind::Plain | TerrainKind::Road | TerrainKind::Rock => 1.,
+      TerrainKind::Sand => TERRAIN_SAND_BULLET_DRAG,
+    }
+  }
+
+  pub fn get_fill_style(&self) -> Option<&'static str> {
+    match self {
+      TerrainKind::Mud => Some(TERRAIN_MUD_FILL_STYLE),
+      TerrainKind::Plain => None,
+      TerrainKind::Road => Some(TERRAIN_ROAD_FILL_STYLE),
+      TerrainKind::Rock => Some(TERRAIN_ROCK_FILL_STYLE),
+      TerrainKind::Sand => Some(TERRAIN_SAND_FILL_STYLE),
+    }
+  }
+
+  // Multiplies the top speed of a tank on the terrain
+  pub fn get_speed_multiplier(&self) -> f64 {
+    match self {
+      TerrainKind::Mud => TERRAIN_MUD_SPEED_MULTIPLIER,
+      TerrainKind::Plain => 1.,
+      TerrainKind::Road => TERRAIN_ROAD_SPEED_MULTIPLIER,
+      TerrainKind::Rock => TERRAIN_ROCK_SPEED_MULTIPLIER,
+      TerrainKind::Sand => TERRAIN_SAND_SPEED_MULTIPLIER,
+    }
+  }
+}
+
+// A grid of terrain cells covering the bounds of the world
+#[derive(Default)]
+pub struct Terrain {
+  // Empty when the terrain is all plain
+  cells: Vec<TerrainKind>,
+}
+
+impl Terrain {
+  pub fn clear(&mut self) {
+    self.cells.clear();
+  }
+
+  // Random patches of mud, sand, and rock from smoothed value noise crossed
+  // by straight roads
+  pub fn generate(&mut self) {
+    let mut thread_rng: ThreadRng = rand::thread_rng();
+    let column_count: usize = Terrain::get_column_count();
+    let row_count: usize = Terrain::get_row_count();
+    let noise_column_count: usize = column_count / TERRAIN_NOISE_CELL_SIZE + 2;
+    let noise_row_count: usize = row_count / TERRAIN_NOISE_CELL_SIZE + 2;
+    let noise: Vec<f64> = (0..noise_column_count * noise_row_count)
+      .map(|_| thread_rng.gen_range(0.0..1.0))
+      .collect();
+    self.cells = (0..column_count * row_count)
+      .map(|index| {
+        let column: usize = index % column_count;
+        let row: usize = index / column_count;
+        let noise_x: f64 = column as f64 / TERRAIN_NOISE_CELL_SIZE as f64;
+        let noise_y: f64 = row as f64 / TERRAIN_NOISE_CELL_SIZE as f64;
+        let noise_column: usize = noise_x as usize;
+        let noise_row: usize = noise_y as usize;
+        let fraction_x: f64 = noise_x.fract();
+        let fraction_y: f64 = noise_y.fract();
+        let sample = |column: usize, row: usize| -> f64 {
+          noise[row * noise_column_count + column]
+        };
+        let top: f64 = sample(noise_column, noise_row) * (1. - fraction_x)
+          + sample(noise_column + 1, noise_row) * fraction_x;
+        let bottom: f64 = sample(noise_column, noise_row + 1)
+          * (1. - fraction_x)
+          + sample(noise_column + 1, noise_row + 1) * fraction_x;
+        let value: f64 = top * (1. - fraction_y) + bottom * fraction_y;
+        if value < TERRAIN_MUD_NOISE_MAX {
+          TerrainKind::Mud
+        } else if value >= TERRAIN_ROCK_NOISE_MIN {
+          TerrainKind::Rock
+        } else if value >= TERRAIN_SAND_NOISE_MIN {
+          TerrainKind::Sand
+        } else {
+          TerrainKind::Plain
+        }
+      })
+      .collect();
+    for road_index in 0..TERRAIN_ROAD_COUNT {
+      // Alternates between horizontal and vertical roads
+      if road_index % 2 == 0 {
+        let row: usize = thread_rng.gen_range(0..row_count);
+        for column in 0..column_count {
+          self.cells[row * column_count + column] = TerrainKind::Road;
+        }
+      } else {
+        let column: usize = thread_rng.gen_range(0..column_count);
+        for row in 0..row_count {
+          self.cells[row * column_count + column] = TerrainKind::Road;
+        }
+      }
+    }
+  }
+
+  pub fn get_bullet_drag(
+    &self,
+    point: &Point2DD,
+  ) -> f64 {
+    self.get_kind(point).get_bullet_drag()
+  }
+
+  pub fn get_cell_count() -> usize {
+    Terrain::get_column_count() * Terrain::get_row_count()
+  }
+
+  pub fn get_cell_index(point: &Point2DD) -> Option<usize> {
+    if point.x < BOUNDS.x_min || point.y < BOUNDS.y_min {
+      return None;
+    }
+    let column = ((point.x - BOUNDS.x_min) / TERRAIN_CELL_SIZE) as usize;
+    let row = ((point.y - BOUNDS.y_min) / TERRAIN_CELL_SIZE) as usize;
+    if column >= Terrain::get_column_count() || row >= Terrain::get_row_count()
+    {
+      return None;
+    }
+    Some(row * Terrain::get_column_count() + column)
+  }
+
+  // The upper left corner of the cell
+  pub fn get_cell_origin(index: usize) -> Point2DD {
+    let column: usize = index % Terrain::get_column_count();
+    let row: usize = index / Terrain::get_column_count();
+    Point2DD::new(
+      BOUNDS.x_min + column as f64 * TERRAIN_CELL_SIZE,
+      BOUNDS.y_min + row as f64 * TERRAIN_CELL_SIZE,
+    )
+  }
+
+  pub fn get_cells(&self) -> &[TerrainKind] {
+    &self.cells
+  }
+
+  fn get_column_count() -> usize {
+    ((BOUNDS.x_max - BOUNDS.x_min) / TERRAIN_CELL_SIZE).ceil() as usize
+  }
+
+  // Plain outside of the bounds
+  pub fn get_kind(
+    &self,
+    point: &Point2DD,
+  ) -> TerrainKind {
+    Terrain::get_cell_index(point)
+      .and_then(|index| self.cells.get(index))
+      .copied()
+      .unwrap_or_default()
+  }
+
+  fn get_row_count() -> usize {
+    ((BOUNDS.y_max - BOUNDS.y_min) / TERRAIN_CELL_SIZE).ceil() as usize
+  }
+
+  pub fn get_speed_multiplier(
+    &self,
+    point: &Point2DD,
+  ) -> f64 {
+    self.get_kind(point).get_speed_multiplier()
+  }
+}